@@ -0,0 +1,50 @@
+//! Micro-benchmarks of the parsing and rule matching, run with `cargo bench -p robotxt`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use robotxt::Robots;
+
+/// Amount of iterations between time checks.
+const BATCH: u32 = 10;
+
+/// Runs the closure repeatedly for about a second and prints the mean time.
+fn bench(name: &str, mut f: impl FnMut()) {
+    // Warms up.
+    for _ in 0..BATCH {
+        f();
+    }
+
+    let mut iters = 0u32;
+    let start = Instant::now();
+    while start.elapsed() < Duration::from_secs(1) {
+        for _ in 0..BATCH {
+            f();
+        }
+
+        iters += BATCH;
+    }
+
+    let mean = start.elapsed() / iters;
+    println!("{name:<24} {mean:>10.2?}/iter ({iters} iters)");
+}
+
+fn main() {
+    let exts = (0..500).map(|i| format!("Disallow: /*.ext{i}$ \n"));
+    let txt = "User-Agent: * \n".to_string() + &exts.collect::<String>();
+
+    bench("parse_ending_rules", || {
+        black_box(Robots::from_bytes(black_box(txt.as_bytes()), "foobot"));
+    });
+
+    let txt = b"User-Agent: * \n Disallow: /a*b*c*d \n Allow: /";
+    let r = Robots::from_bytes(txt, "foobot");
+
+    bench("universal_match", || {
+        black_box(r.is_relative_allowed(black_box("/a/xx/b/yy/c/zz/d.html")));
+    });
+
+    bench("universal_mismatch", || {
+        black_box(r.is_relative_allowed(black_box("/a/xx/b/yy/c/zz/e.html")));
+    });
+}
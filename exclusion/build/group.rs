@@ -1,7 +1,9 @@
-use std::collections::HashSet;
 use std::fmt::{Display, Formatter};
+use std::time::Duration;
 
-use crate::build::format_comment;
+use time::Time;
+
+use crate::build::{format_comment, BuildWarning, BuildWarningKind};
 use crate::paths::normalize_path;
 
 /// The single formatted `user-agent` group.
@@ -9,10 +11,14 @@ use crate::paths::normalize_path;
 /// See [crate::RobotsBuilder::group].
 #[derive(Debug, Default, Clone)]
 pub struct GroupBuilder {
-    user_agents: HashSet<String>,
-    rules_disallow: Vec<String>,
-    rules_allow: Vec<String>,
-    delay: Option<u16>,
+    user_agents: Vec<String>,
+    rules_disallow: Vec<(String, Option<String>)>,
+    rules_allow: Vec<(String, Option<String>)>,
+    rules_noindex: Vec<String>,
+    delay: Option<Duration>,
+    request_rate: Option<(u32, Duration)>,
+    visit_time: Option<(Time, Time)>,
+    empty_patterns: bool,
 
     header: Option<String>,
     footer: Option<String>,
@@ -24,6 +30,26 @@ impl GroupBuilder {
         Self::default()
     }
 
+    /// Adds a `User-Agent` directive.
+    /// Repeated identical user-agents are written once.
+    ///
+    /// ```
+    /// use robotxt::GroupBuilder;
+    ///
+    /// let group = GroupBuilder::new()
+    ///     .user_agent("foobot")
+    ///     .user_agent("barbot")
+    ///     .disallow("/");
+    /// ```
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        let user_agent = user_agent.trim();
+        if !self.user_agents.iter().any(|u| u == user_agent) {
+            self.user_agents.push(user_agent.to_string());
+        }
+
+        self
+    }
+
     /// Adds a local header, usually used for rule notes.
     ///
     /// ```
@@ -43,6 +69,7 @@ impl GroupBuilder {
     }
 
     /// Adds an `Allow` directive.
+    /// Repeated identical patterns are written once.
     ///
     /// ```
     /// use robotxt::RobotsBuilder;
@@ -53,12 +80,30 @@ impl GroupBuilder {
     ///     });
     /// ```
     pub fn allow(mut self, rule: &str) -> Self {
-        let rule = normalize_path(rule);
-        self.rules_allow.push(rule);
+        let rule = self.normalize_rule(rule);
+        push_rule(&mut self.rules_allow, rule, None);
+        self
+    }
+
+    /// Adds an `Allow` directive with the trailing comment.
+    /// See [`GroupBuilder::allow`].
+    ///
+    /// ```
+    /// use robotxt::RobotsBuilder;
+    ///
+    /// let txt = RobotsBuilder::default()
+    ///     .group(["foobot"], |u| {
+    ///         u.disallow("/").allow_with_comment("/public/", "open data")
+    ///     });
+    /// ```
+    pub fn allow_with_comment(mut self, rule: &str, comment: &str) -> Self {
+        let rule = self.normalize_rule(rule);
+        push_rule(&mut self.rules_allow, rule, Some(comment));
         self
     }
 
     /// Adds a `Disallow` directive.
+    /// Repeated identical patterns are written once.
     ///
     /// ```
     /// use robotxt::RobotsBuilder;
@@ -69,8 +114,47 @@ impl GroupBuilder {
     ///     });
     /// ```
     pub fn disallow(mut self, rule: &str) -> Self {
-        let rule = normalize_path(rule);
-        self.rules_disallow.push(rule);
+        let rule = self.normalize_rule(rule);
+        push_rule(&mut self.rules_disallow, rule, None);
+        self
+    }
+
+    /// Adds a `Disallow` directive with the trailing comment.
+    /// See [`GroupBuilder::disallow`].
+    ///
+    /// ```
+    /// use robotxt::RobotsBuilder;
+    ///
+    /// let txt = RobotsBuilder::default()
+    ///     .group(["foobot"], |u| {
+    ///         u.disallow_with_comment("/tmp", "build artifacts")
+    ///     });
+    /// ```
+    pub fn disallow_with_comment(mut self, rule: &str, comment: &str) -> Self {
+        let rule = self.normalize_rule(rule);
+        push_rule(&mut self.rules_disallow, rule, Some(comment));
+        self
+    }
+
+    /// Adds a `Noindex` directive.
+    ///
+    /// Note that the `Noindex` directive is not a part of the RFC 9309
+    /// and is ignored by most crawlers.
+    ///
+    /// ```
+    /// use robotxt::RobotsBuilder;
+    ///
+    /// let txt = RobotsBuilder::default()
+    ///     .group(["foobot"], |u| {
+    ///         u.allow("/").noindex("/drafts/")
+    ///     });
+    /// ```
+    pub fn noindex(mut self, rule: &str) -> Self {
+        let rule = self.normalize_rule(rule);
+        if !self.rules_noindex.contains(&rule) {
+            self.rules_noindex.push(rule);
+        }
+
         self
     }
 
@@ -85,10 +169,52 @@ impl GroupBuilder {
     ///     });
     /// ```
     pub fn crawl_delay(mut self, delay: u16) -> Self {
+        self.delay = Some(Duration::from_secs(delay.into()));
+        self
+    }
+
+    /// Adds a `Crawl-Delay` directive with the fractional delay.
+    #[cfg(feature = "parser")]
+    pub(crate) fn crawl_delay_duration(mut self, delay: Duration) -> Self {
         self.delay = Some(delay);
         self
     }
 
+    /// Adds a non-standard `Request-rate` directive i.e. the amount of
+    /// requests per the period of time. The period is written in whole
+    /// seconds.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use robotxt::RobotsBuilder;
+    ///
+    /// let txt = RobotsBuilder::default()
+    ///     .group(["foobot"], |u| {
+    ///         u.request_rate(1, Duration::from_secs(10))
+    ///     });
+    /// ```
+    pub fn request_rate(mut self, requests: u32, per: Duration) -> Self {
+        self.request_rate = Some((requests, per));
+        self
+    }
+
+    /// Adds a non-standard `Visit-time` directive i.e. the UTC window,
+    /// during which crawling is preferred. Seconds are not written.
+    ///
+    /// ```
+    /// use robotxt::RobotsBuilder;
+    /// use robotxt::time::Time;
+    ///
+    /// let start = Time::from_hms(6, 0, 0).unwrap();
+    /// let end = Time::from_hms(8, 45, 0).unwrap();
+    /// let txt = RobotsBuilder::default()
+    ///     .group(["foobot"], |u| u.visit_time(start, end));
+    /// ```
+    pub fn visit_time(mut self, start: Time, end: Time) -> Self {
+        self.visit_time = Some((start, end));
+        self
+    }
+
     /// Adds a local footer, usually used for rule notes.
     ///
     /// ```
@@ -107,13 +233,68 @@ impl GroupBuilder {
     }
 }
 
+impl GroupBuilder {
+    /// Reports conflicting rules, empty patterns and missing rules.
+    pub(crate) fn validate(&self) -> Vec<BuildWarning> {
+        let mut user_agents = self.user_agents.clone();
+        if user_agents.is_empty() {
+            user_agents.push("*".to_string());
+        }
+
+        let mut warnings = Vec::new();
+        let warn = |kind, pattern| BuildWarning::new(kind, &user_agents, pattern);
+
+        if self.rules_allow.is_empty() && self.rules_disallow.is_empty() {
+            warnings.push(warn(BuildWarningKind::NoRules, None));
+        }
+
+        if self.empty_patterns {
+            warnings.push(warn(BuildWarningKind::EmptyPattern, Some("")));
+        }
+
+        let conflicts = self.rules_allow.iter().map(|(rule, _)| rule);
+        let conflicts =
+            conflicts.filter(|rule| self.rules_disallow.iter().any(|(u, _)| &u == rule));
+        for rule in conflicts {
+            let kind = BuildWarningKind::ConflictingRule;
+            warnings.push(warn(kind, Some(rule.as_str())));
+        }
+
+        warnings
+    }
+
+    /// Returns the normalized pattern, remembering if it was empty.
+    fn normalize_rule(&mut self, rule: &str) -> String {
+        self.empty_patterns |= rule.trim().is_empty();
+        normalize_path(rule)
+    }
+}
+
+/// Appends the normalized rule with the single-line comment, unless
+/// the same pattern was already added.
+fn push_rule(rules: &mut Vec<(String, Option<String>)>, rule: String, comment: Option<&str>) {
+    if rules.iter().any(|(u, _)| u == &rule) {
+        return;
+    }
+
+    let comment = comment.map(|c| c.split_whitespace().collect::<Vec<_>>().join(" "));
+    let comment = comment.filter(|c| !c.is_empty());
+    rules.push((rule, comment));
+}
+
+/// Formats the rule line with the optional trailing comment.
+fn format_rule(directive: &str, rule: &str, comment: Option<&String>) -> String {
+    match comment {
+        Some(comment) => format!("{directive}: {rule}  # {comment}"),
+        None => format!("{directive}: {rule}"),
+    }
+}
+
 impl<'ua> FromIterator<&'ua str> for GroupBuilder {
     fn from_iter<T: IntoIterator<Item = &'ua str>>(iter: T) -> Self {
-        let uas = iter.into_iter().map(|ua| ua.trim().to_string());
-        Self {
-            user_agents: uas.collect(),
-            ..Self::default()
-        }
+        // Keeps the insertion order for the deterministic output.
+        let iter = iter.into_iter();
+        iter.fold(Self::default(), |group, ua| group.user_agent(ua))
     }
 }
 
@@ -121,7 +302,16 @@ impl Display for GroupBuilder {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let header = self.header.as_ref().map(|h| format_comment(h));
         let footer = self.footer.as_ref().map(|f| format_comment(f));
-        let delay = self.delay.map(|d| format!("Crawl-Delay: {d}"));
+        let delay = self
+            .delay
+            .map(|d| format!("Crawl-Delay: {}", d.as_secs_f64()));
+        let rate = self
+            .request_rate
+            .map(|(n, per)| format!("Request-rate: {n}/{}s", per.as_secs()));
+        let visit = self.visit_time.map(|(start, end)| {
+            let format = |t: Time| format!("{:02}{:02}", t.hour(), t.minute());
+            format!("Visit-time: {}-{}", format(start), format(end))
+        });
 
         let agents = if self.user_agents.is_empty() {
             Some("User-Agent: *".to_string())
@@ -135,7 +325,7 @@ impl Display for GroupBuilder {
             None
         } else {
             let rd = self.rules_disallow.iter();
-            let rd = rd.map(|r| format!("Disallow: {r}"));
+            let rd = rd.map(|(r, c)| format_rule("Disallow", r, c.as_ref()));
             Some(rd.collect::<Vec<_>>().join("\n"))
         };
 
@@ -149,11 +339,21 @@ impl Display for GroupBuilder {
             }
         } else {
             let rd = self.rules_allow.iter();
-            let rd = rd.map(|r| format!("Allow: {r}"));
+            let rd = rd.map(|(r, c)| format_rule("Allow", r, c.as_ref()));
             Some(rd.collect::<Vec<_>>().join("\n"))
         };
 
-        let result = [header, agents, delay, disallows, allows, footer];
+        let noindexes = if self.rules_noindex.is_empty() {
+            None
+        } else {
+            let rd = self.rules_noindex.iter();
+            let rd = rd.map(|r| format!("Noindex: {r}"));
+            Some(rd.collect::<Vec<_>>().join("\n"))
+        };
+
+        let result = [
+            header, agents, delay, rate, visit, disallows, allows, noindexes, footer,
+        ];
         let result = result.iter().filter_map(|u| u.clone());
         let result = result.collect::<Vec<_>>().join("\n");
         write!(f, "{}", result.as_str())
@@ -170,9 +370,96 @@ mod builder {
         assert!(r.contains("User-Agent: *"));
     }
 
+    #[test]
+    fn ordered_uas() {
+        let uas = ["foobot", "barbot", "bazbot", "foobot", "quxbot"];
+        let r0 = GroupBuilder::from_iter(uas).disallow("/").to_string();
+        let r1 = GroupBuilder::from_iter(uas).disallow("/").to_string();
+        assert_eq!(r0, r1);
+
+        let exp = "User-Agent: foobot\nUser-Agent: barbot\nUser-Agent: bazbot\nUser-Agent: quxbot";
+        assert!(r0.starts_with(exp));
+    }
+
     #[test]
     fn no_rules() {
         let r = GroupBuilder::from_iter(["foobot"]).to_string();
         assert!(r.contains("Allow: *"));
     }
+
+    #[test]
+    fn noindex() {
+        let r = GroupBuilder::new().allow("/").noindex("/foo").to_string();
+        assert!(r.ends_with("Allow: /\nNoindex: /foo"));
+    }
+
+    #[test]
+    fn duplicate_rules() {
+        let r = GroupBuilder::new()
+            .disallow("/x")
+            .disallow("/y")
+            .disallow("/x")
+            .to_string();
+        assert_eq!(r, "User-Agent: *\nDisallow: /x\nDisallow: /y");
+    }
+
+    #[test]
+    fn rule_comments() {
+        let r = GroupBuilder::new()
+            .disallow_with_comment("/tmp", "build\n artifacts")
+            .disallow_with_comment("/tmp", "ignored")
+            .allow_with_comment("/tmp/public", " ")
+            .to_string();
+
+        let exp = "User-Agent: *\nDisallow: /tmp  # build artifacts\nAllow: /tmp/public";
+        assert_eq!(r, exp);
+    }
+
+    #[test]
+    fn validate() {
+        let r = GroupBuilder::from_iter(["foobot", "barbot"])
+            .allow("/x")
+            .disallow("")
+            .disallow("/x")
+            .validate();
+
+        let kinds: Vec<_> = r.iter().map(|u| (u.kind, u.pattern.as_deref())).collect();
+        let exp = vec![
+            (BuildWarningKind::EmptyPattern, Some("")),
+            (BuildWarningKind::ConflictingRule, Some("/x")),
+        ];
+
+        assert_eq!(kinds, exp);
+        assert_eq!(r[0].user_agents, ["foobot", "barbot"]);
+    }
+
+    #[test]
+    fn validate_no_rules() {
+        let r = GroupBuilder::from_iter(["foobot"]).noindex("/x").validate();
+        assert_eq!(r.len(), 1);
+        assert_eq!(r[0].kind, BuildWarningKind::NoRules);
+
+        let r = GroupBuilder::from_iter(["foobot"]).allow("/").validate();
+        assert!(r.is_empty());
+    }
+
+    #[test]
+    fn empty_pattern() {
+        let r = GroupBuilder::new().disallow("").to_string();
+        assert!(r.ends_with("Disallow: /"));
+    }
+
+    #[test]
+    fn rate_and_visit() {
+        let start = Time::from_hms(6, 0, 0).unwrap();
+        let end = Time::from_hms(8, 45, 0).unwrap();
+        let r = GroupBuilder::new()
+            .request_rate(3, Duration::from_secs(300))
+            .visit_time(start, end)
+            .disallow("/")
+            .to_string();
+
+        let exp = "User-Agent: *\nRequest-rate: 3/300s\nVisit-time: 0600-0845\nDisallow: /";
+        assert_eq!(r, exp);
+    }
 }
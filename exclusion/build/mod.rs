@@ -1,20 +1,23 @@
-use std::collections::HashSet;
 use std::fmt;
 
 use url::Url;
 
 pub use crate::build::group::GroupBuilder;
 use crate::build::split::format_comment;
+pub use crate::build::warning::{BuildWarning, BuildWarningKind};
+use crate::{Error, Result, BYTE_LIMIT};
 
 mod group;
 mod split;
+mod warning;
 
 /// The set of formatted `user-agent` groups that can be written
 /// in the `robots.txt` compliant format.
 #[derive(Debug, Default, Clone)]
 pub struct RobotsBuilder {
     groups: Vec<GroupBuilder>,
-    sitemaps: HashSet<Url>,
+    sitemaps: Vec<Url>,
+    host: Option<String>,
     header: Option<String>,
     footer: Option<String>,
 }
@@ -59,6 +62,41 @@ impl RobotsBuilder {
         self
     }
 
+    /// Adds all `user-agent` groups from the iterator, e.g. assembled
+    /// elsewhere from the structured data.
+    ///
+    /// ```
+    /// use robotxt::{GroupBuilder, RobotsBuilder};
+    ///
+    /// let groups = vec![
+    ///     GroupBuilder::new().user_agent("foobot").disallow("/"),
+    ///     GroupBuilder::from_iter(["barbot", "bazbot"]).allow("/"),
+    /// ];
+    ///
+    /// let txt = RobotsBuilder::default().extend_groups(groups);
+    /// ```
+    pub fn extend_groups(mut self, groups: impl IntoIterator<Item = GroupBuilder>) -> Self {
+        self.groups.extend(groups);
+        self
+    }
+
+    /// Adds a new single `user-agent` group with the `Crawl-Delay` directive
+    /// and no rules for each of the provided `(user-agent, delay)` pairs.
+    ///
+    /// ```
+    /// use robotxt::RobotsBuilder;
+    ///
+    /// let txt = RobotsBuilder::default()
+    ///     .group(["*"], |u| u.disallow("/private/"))
+    ///     .bot_delays(&[("foobot", 5), ("barbot", 10)]);
+    /// ```
+    pub fn bot_delays(self, delays: &[(&str, u16)]) -> Self {
+        let delays = delays.iter();
+        delays.fold(self, |builder, &(user_agent, delay)| {
+            builder.group([user_agent], |u| u.crawl_delay(delay))
+        })
+    }
+
     /// Adds the `Sitemap` directive from the URL address.
     ///
     /// ```
@@ -70,7 +108,28 @@ impl RobotsBuilder {
     ///     .sitemap("https://example.com/sitemap_1.xml".try_into().unwrap());
     /// ```
     pub fn sitemap(mut self, sitemap: Url) -> Self {
-        self.sitemaps.insert(sitemap);
+        if !self.sitemaps.contains(&sitemap) {
+            self.sitemaps.push(sitemap);
+        }
+
+        self
+    }
+
+    /// Adds the `Host` directive i.e. the preferred domain of the site,
+    /// written after all `user-agent` groups.
+    ///
+    /// Note that the `Host` directive is not a part of the RFC 9309
+    /// and is ignored by most crawlers.
+    ///
+    /// ```
+    /// use robotxt::RobotsBuilder;
+    ///
+    /// let txt = RobotsBuilder::default()
+    ///     .group(["*"], |u| u.disallow("/"))
+    ///     .host("example.com");
+    /// ```
+    pub fn host(mut self, host: &str) -> Self {
+        self.host = Some(host.trim().to_string());
         self
     }
 
@@ -89,6 +148,135 @@ impl RobotsBuilder {
         self
     }
 
+    /// Creates a new [`RobotsBuilder`] from the parsed [`Robots`].
+    ///
+    /// Reconstructs the single `user-agent` group from the retained rules,
+    /// crawl-delay, request-rate and visit-time, and the sitemaps. As [`Robots`] only keeps the rules
+    /// of the matched user-agent, this round-trips a single-agent view,
+    /// not the whole original file.
+    ///
+    /// ```
+    /// use robotxt::{Robots, RobotsBuilder};
+    ///
+    /// let txt = r#"
+    ///     User-Agent: foobot
+    ///     Crawl-Delay: 2.5
+    ///     Disallow: /example/
+    ///     User-Agent: barbot
+    ///     Disallow: /
+    /// "#.as_bytes();
+    ///
+    /// let r0 = Robots::from_bytes(txt, "foobot");
+    /// let r1 = RobotsBuilder::from_robots(&r0).parse("foobot");
+    /// assert_eq!(r0, r1);
+    /// ```
+    ///
+    /// [`Robots`]: crate::Robots
+    #[cfg(feature = "parser")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "parser")))]
+    pub fn from_robots(robots: &crate::Robots) -> Self {
+        let builder = robots.sitemaps().iter().cloned();
+        let builder = builder.fold(Self::new(), |u, sitemap| u.sitemap(sitemap));
+
+        builder.group([robots.user_agent_original()], |mut u| {
+            if let Some(delay) = robots.crawl_delay() {
+                u = u.crawl_delay_duration(delay);
+            }
+
+            if let Some(rate) = robots.request_rate() {
+                u = u.request_rate(rate.requests, rate.per);
+            }
+
+            if let Some((start, end)) = robots.visit_time() {
+                u = u.visit_time(start, end);
+            }
+
+            if robots.is_always() == Some(false) {
+                u = u.disallow("/");
+            }
+
+            robots
+                .patterns()
+                .fold(u, |u, (pattern, allow)| match allow {
+                    true => u.allow(pattern),
+                    false => u.disallow(pattern),
+                })
+        })
+    }
+
+    /// Reports questionable groups i.e. directly conflicting rules, empty
+    /// patterns and groups without rules, without failing the build.
+    ///
+    /// ```
+    /// use robotxt::{BuildWarningKind, RobotsBuilder};
+    ///
+    /// let txt = RobotsBuilder::default()
+    ///     .group(["foobot"], |u| u.allow("/x").disallow("/x"))
+    ///     .group(["barbot"], |u| u.crawl_delay(5));
+    ///
+    /// let warnings = txt.validate();
+    /// assert_eq!(warnings[0].kind, BuildWarningKind::ConflictingRule);
+    /// assert_eq!(warnings[0].user_agents, ["foobot"]);
+    /// assert_eq!(warnings[0].pattern.as_deref(), Some("/x"));
+    /// assert_eq!(warnings[1].kind, BuildWarningKind::NoRules);
+    /// ```
+    pub fn validate(&self) -> Vec<BuildWarning> {
+        self.groups.iter().flat_map(|u| u.validate()).collect()
+    }
+
+    /// Returns the constructed output as UTF-8 bytes, terminated with
+    /// the trailing newline, ready to be written into the file.
+    ///
+    /// ```
+    /// use robotxt::RobotsBuilder;
+    ///
+    /// let txt = RobotsBuilder::default().group(["*"], |u| u.disallow("/"));
+    /// assert_eq!(txt.to_bytes(), b"User-Agent: *\nDisallow: /\n");
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut txt = self.to_string();
+        if !txt.ends_with('\n') {
+            txt.push('\n');
+        }
+
+        txt.into_bytes()
+    }
+
+    /// Returns the length of the constructed output in bytes.
+    ///
+    /// ```
+    /// use robotxt::RobotsBuilder;
+    ///
+    /// let txt = RobotsBuilder::default().group(["*"], |u| u.disallow("/"));
+    /// assert_eq!(txt.len_bytes(), 25);
+    /// ```
+    pub fn len_bytes(&self) -> usize {
+        self.to_string().len()
+    }
+
+    /// Returns the constructed output, or [`Error::ByteLimit`] if it exceeds
+    /// the [`BYTE_LIMIT`], as the rest would be ignored by conformant parsers.
+    ///
+    /// ```
+    /// use robotxt::RobotsBuilder;
+    ///
+    /// let txt = RobotsBuilder::default().group(["*"], |u| u.disallow("/"));
+    /// assert_eq!(txt.try_to_string().unwrap(), "User-Agent: *\nDisallow: /");
+    /// ```
+    ///
+    /// [`Error::ByteLimit`]: crate::Error::ByteLimit
+    /// [`BYTE_LIMIT`]: crate::BYTE_LIMIT
+    pub fn try_to_string(&self) -> Result<String> {
+        let txt = self.to_string();
+        match txt.len() {
+            size if size > BYTE_LIMIT => Err(Error::ByteLimit {
+                size,
+                limit: BYTE_LIMIT,
+            }),
+            _ => Ok(txt),
+        }
+    }
+
     /// Parses the constructed output.
     /// See [`Robots::from_bytes`].
     ///
@@ -109,7 +297,17 @@ impl fmt::Display for RobotsBuilder {
         let groups = self.groups.iter().map(|u| u.to_string());
         let groups = groups.collect::<Vec<_>>().join("\n\n");
 
-        let result = [header, Some(groups), footer];
+        let sitemaps = if self.sitemaps.is_empty() {
+            None
+        } else {
+            let sm = self.sitemaps.iter();
+            let sm = sm.map(|u| format!("Sitemap: {u}"));
+            Some(sm.collect::<Vec<_>>().join("\n"))
+        };
+
+        let host = self.host.as_ref().map(|h| format!("Host: {h}"));
+
+        let result = [header, Some(groups), sitemaps, host, footer];
         let result = result.iter().filter_map(|u| u.clone());
         let result = result.collect::<Vec<_>>().join("\n\n");
         write!(f, "{}", result.as_str())
@@ -118,7 +316,23 @@ impl fmt::Display for RobotsBuilder {
 
 #[cfg(test)]
 mod builder {
-    use crate::{Result, RobotsBuilder};
+    use crate::{Error, GroupBuilder, Result, RobotsBuilder, BYTE_LIMIT};
+
+    #[test]
+    fn extend_groups() {
+        let groups = [
+            GroupBuilder::new().user_agent("foobot").disallow("/a"),
+            GroupBuilder::from_iter(["barbot", "bazbot"]).allow("/b"),
+        ];
+
+        let r0 = RobotsBuilder::default().extend_groups(groups).to_string();
+        let r1 = RobotsBuilder::default()
+            .group(["foobot"], |u| u.disallow("/a"))
+            .group(["barbot", "bazbot"], |u| u.allow("/b"))
+            .to_string();
+
+        assert_eq!(r0, r1);
+    }
 
     #[test]
     fn readme() -> Result<()> {
@@ -138,9 +352,103 @@ mod builder {
             })
             .sitemap("https://example.com/sitemap_1.xml".try_into()?)
             .sitemap("https://example.com/sitemap_2.xml".try_into()?)
+            .host("example.com")
             .footer("Robots.txt: End");
 
-        println!("{}", txt.to_string());
+        println!("{txt}");
         Ok(())
     }
+
+    #[test]
+    fn to_bytes() {
+        let txt = RobotsBuilder::default()
+            .header("Robots.txt: Start")
+            .group(["foobot"], |u| u.disallow("/"));
+
+        let exp = txt.to_string() + "\n";
+        assert_eq!(txt.to_bytes(), exp.into_bytes());
+    }
+
+    #[test]
+    fn byte_limit() {
+        let pattern = format!("/{}", "a".repeat(1024));
+        let txt = (0..500).fold(RobotsBuilder::default(), |u, i| {
+            u.group(["foobot"], |u| u.disallow(&format!("{pattern}{i}")))
+        });
+
+        assert!(txt.len_bytes() > BYTE_LIMIT);
+        let err = txt.try_to_string().unwrap_err();
+        assert!(matches!(
+            err,
+            Error::ByteLimit {
+                limit: BYTE_LIMIT,
+                ..
+            }
+        ));
+
+        let txt = RobotsBuilder::default().group(["foobot"], |u| u.disallow(&pattern));
+        assert_eq!(txt.try_to_string().unwrap(), txt.to_string());
+    }
+
+    #[test]
+    fn bot_delays() {
+        let txt = RobotsBuilder::default()
+            .bot_delays(&[("foobot", 5), ("barbot", 10)])
+            .to_string();
+
+        let exp = "User-Agent: foobot\nCrawl-Delay: 5\nAllow: *\n\n\
+            User-Agent: barbot\nCrawl-Delay: 10\nAllow: *";
+        assert_eq!(txt, exp);
+    }
+
+    #[test]
+    fn host() {
+        let txt = RobotsBuilder::default()
+            .group(["foobot"], |u| u.disallow("/").noindex("/foo"))
+            .host("example.com")
+            .footer("Robots.txt: End")
+            .to_string();
+
+        let exp = "User-Agent: foobot\nDisallow: /\nNoindex: /foo\n\nHost: example.com\n\n# Robots.txt: End";
+        assert_eq!(txt, exp);
+    }
+
+    #[cfg(feature = "parser")]
+    #[test]
+    fn from_robots() {
+        let txt = r#"
+            User-Agent: barbot
+            Disallow: /
+
+            User-Agent: FooBot
+            Crawl-Delay: 0.5
+            Request-rate: 3/5m
+            Visit-time: 2200-0600
+            Allow: /example/yeah.txt
+            Disallow: /example/
+            Disallow: /*.php$
+
+            Sitemap: https://example.com/sitemap_1.xml
+        "#;
+
+        let r0 = crate::Robots::from_bytes(txt.as_bytes(), "foobot");
+        let builder = RobotsBuilder::from_robots(&r0);
+        let r1 = builder.parse("foobot");
+
+        assert_eq!(r0, r1);
+        assert!(r1.is_relative_allowed("/example/yeah.txt"));
+        assert!(!r1.is_relative_allowed("/example/nope.txt"));
+        assert!(!r1.is_relative_allowed("/index.php"));
+        assert_eq!(r1.crawl_delay_secs(), Some(0.5));
+        assert_eq!(r1.request_rate(), r0.request_rate());
+        assert_eq!(r1.visit_time(), r0.visit_time());
+        assert!(r1.visit_time().is_some());
+        assert_eq!(r1.sitemaps(), r0.sitemaps());
+        assert_eq!(r1.user_agent_original(), "FooBot");
+
+        let r0 = crate::Robots::from_bytes(txt.as_bytes(), "barbot");
+        let r1 = RobotsBuilder::from_robots(&r0).parse("barbot");
+        assert_eq!(r0.is_always(), r1.is_always());
+        assert!(!r1.is_relative_allowed("/example/yeah.txt"));
+    }
 }
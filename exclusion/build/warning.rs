@@ -0,0 +1,62 @@
+use std::fmt;
+
+/// The kind of the [`BuildWarning`] i.e. the questionable part of the group.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BuildWarningKind {
+    /// The same pattern is both allowed and disallowed.
+    ConflictingRule,
+    /// The pattern is empty i.e. the rule is written as `/`.
+    EmptyPattern,
+    /// The group has neither `Allow` nor `Disallow` rules.
+    NoRules,
+}
+
+impl fmt::Display for BuildWarningKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let inner = match self {
+            Self::ConflictingRule => "conflicting-rule",
+            Self::EmptyPattern => "empty-pattern",
+            Self::NoRules => "no-rules",
+        };
+
+        fmt::Display::fmt(inner, f)
+    }
+}
+
+/// The single questionable group found by [`RobotsBuilder::validate`].
+///
+/// [`RobotsBuilder::validate`]: crate::RobotsBuilder::validate
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildWarning {
+    /// The kind of the warning.
+    pub kind: BuildWarningKind,
+    /// The user-agents of the group, in insertion order.
+    pub user_agents: Vec<String>,
+    /// The offending pattern, if any.
+    pub pattern: Option<String>,
+}
+
+impl BuildWarning {
+    pub(crate) fn new(
+        kind: BuildWarningKind,
+        user_agents: &[String],
+        pattern: Option<&str>,
+    ) -> Self {
+        Self {
+            kind,
+            user_agents: user_agents.to_vec(),
+            pattern: pattern.map(ToString::to_string),
+        }
+    }
+}
+
+impl fmt::Display for BuildWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let user_agents = self.user_agents.join(", ");
+        match &self.pattern {
+            Some(pattern) => write!(f, "{user_agents}: `{pattern}` ({})", self.kind),
+            None => write!(f, "{user_agents}: ({})", self.kind),
+        }
+    }
+}
@@ -3,13 +3,22 @@
 #![doc = include_str!("./README.md")]
 
 // Re-exports
+#[cfg(any(feature = "builder", feature = "parser"))]
+pub use time;
 pub use url;
 
 #[cfg(feature = "builder")]
-pub use build::{GroupBuilder, RobotsBuilder};
+pub use build::{BuildWarning, BuildWarningKind, GroupBuilder, RobotsBuilder};
+#[cfg(all(feature = "parser", feature = "serde"))]
+pub use parse::RulesSnapshot;
 #[cfg(feature = "parser")]
-pub use parse::{AccessResult, Robots, ALL_UAS};
-pub use paths::{create_url, BYTE_LIMIT};
+pub use parse::{
+    lint, AccessResult, Directive, Document, Lint, LintKind, ParseOptions, ParseReport,
+    RequestRate, Robots, Rule, RuleError, RuleSet, Span, WildcardKind, ALL_UAS,
+};
+#[cfg(feature = "http")]
+pub use paths::{create_request, create_request_with_agent};
+pub use paths::{create_url, create_url_with_schemes, BYTE_LIMIT, PATH_LEN_LIMIT};
 
 /// Unrecoverable failure during `robots.txt` building or parsing.
 ///
@@ -23,14 +32,29 @@ pub enum Error {
     CannotBeBase,
 
     /// Unable to create the expected path to the `robots.txt` file:
-    /// unexpected address scheme, expected `http` or `https`.
-    #[error("addr scheme: `{scheme}`, expected `http` or `https`")]
+    /// unexpected address scheme, expected `http` or `https` by default.
+    /// See [`create_url_with_schemes`].
+    #[error("addr scheme: `{scheme}` is not allowed")]
     WrongScheme { scheme: String },
 
     /// Unable to create the expected path to the `robots.txt` file:
     /// unexpected parsing error.
     #[error("url parsing error: {0}")]
     Url(#[from] url::ParseError),
+
+    /// Unable to create the request to the `robots.txt` file:
+    /// unexpected request building error.
+    #[cfg(feature = "http")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "http")))]
+    #[error("http error: {0}")]
+    Http(#[from] http::Error),
+
+    /// Unable to build the `robots.txt` file: the rendered output exceeds
+    /// the [`BYTE_LIMIT`], so its tail would be ignored by parsers.
+    #[cfg(feature = "builder")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "builder")))]
+    #[error("output size: {size} bytes exceeds the limit of {limit} bytes")]
+    ByteLimit { size: usize, limit: usize },
 }
 
 /// A specialized [`Result`] type for [`robotxt`] operations.
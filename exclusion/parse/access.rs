@@ -50,7 +50,72 @@ pub enum AccessResult<'a> {
     Unreachable,
 }
 
-impl AccessResult<'_> {
+impl<'a> AccessResult<'a> {
+    /// The maximal amount of consecutive redirects to follow.
+    const REDIRECT_LIMIT: usize = 5;
+
+    /// Returns the result of the attempt, that took the given amount of
+    /// consecutive redirects and ended with the given status code and body.
+    ///
+    /// Returns [`AccessResult::Redirect`] if there are more than five
+    /// redirects (or the final response is still a redirect), otherwise
+    /// maps the final status: `2xx` is successful, `4xx` is unavailable,
+    /// and any other status is unreachable.
+    ///
+    /// ```rust
+    /// use robotxt::AccessResult;
+    ///
+    /// let body = b"User-Agent: * \n Disallow: /";
+    /// let r = AccessResult::after_redirects(5, 200, body);
+    /// assert!(matches!(r, AccessResult::Successful(_)));
+    ///
+    /// let r = AccessResult::after_redirects(6, 200, body);
+    /// assert!(matches!(r, AccessResult::Redirect));
+    /// ```
+    pub fn after_redirects(count: usize, final_status: u16, body: &'a [u8]) -> Self {
+        if count > Self::REDIRECT_LIMIT {
+            return Self::Redirect;
+        }
+
+        Self::from_status(final_status, body)
+    }
+
+    /// Returns the result of the attempt, that ended with the given status
+    /// code and body, according to 2.3.1. Access Results:
+    ///
+    /// - `2xx` is [`AccessResult::Successful`].
+    /// - `3xx` is [`AccessResult::Redirect`], even without the `Location`
+    ///   header i.e. when the redirect cannot be followed.
+    /// - `4xx` is [`AccessResult::Unavailable`], including `429`.
+    /// - Any other status (`5xx`, `1xx` or non-standard) is
+    ///   [`AccessResult::Unreachable`].
+    ///
+    /// ```rust
+    /// use robotxt::AccessResult;
+    ///
+    /// let r = AccessResult::from_status(200, b"User-Agent: * \n Disallow: /");
+    /// assert!(matches!(r, AccessResult::Successful(_)));
+    ///
+    /// let r = AccessResult::from_status(503, b"");
+    /// assert!(matches!(r, AccessResult::Unreachable));
+    /// ```
+    pub fn from_status(status: u16, body: &'a [u8]) -> Self {
+        match status {
+            200..=299 => Self::Successful(body),
+            300..=399 => Self::Redirect,
+            400..=499 => Self::Unavailable,
+            _ => Self::Unreachable,
+        }
+    }
+
+    /// Returns the result of the attempt, that ended with the given status
+    /// code and body. See [`AccessResult::from_status`].
+    #[cfg(feature = "http")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "http")))]
+    pub fn from_status_code(status: http::StatusCode, body: &'a [u8]) -> Self {
+        Self::from_status(status.as_u16(), body)
+    }
+
     /// Returns the textual representation of a status.
     pub fn as_str(&self) -> &'static str {
         match self {
@@ -67,3 +132,58 @@ impl std::fmt::Display for AccessResult<'_> {
         write!(f, "{}", self.as_str())
     }
 }
+
+#[cfg(test)]
+mod redirects {
+    use super::AccessResult;
+
+    const BODY: &[u8] = b"User-Agent: * \n Disallow: /";
+
+    #[test]
+    fn within_limit() {
+        let r = AccessResult::after_redirects(5, 200, BODY);
+        assert!(matches!(r, AccessResult::Successful(BODY)));
+
+        let r = AccessResult::after_redirects(0, 404, BODY);
+        assert!(matches!(r, AccessResult::Unavailable));
+
+        let r = AccessResult::after_redirects(3, 503, BODY);
+        assert!(matches!(r, AccessResult::Unreachable));
+
+        let r = AccessResult::after_redirects(5, 301, BODY);
+        assert!(matches!(r, AccessResult::Redirect));
+    }
+
+    #[test]
+    fn from_status() {
+        let r = AccessResult::from_status(204, BODY);
+        assert!(matches!(r, AccessResult::Successful(BODY)));
+
+        let r = AccessResult::from_status(304, BODY);
+        assert!(matches!(r, AccessResult::Redirect));
+
+        let r = AccessResult::from_status(429, BODY);
+        assert!(matches!(r, AccessResult::Unavailable));
+
+        for status in [100, 500, 599, 600] {
+            let r = AccessResult::from_status(status, BODY);
+            assert!(matches!(r, AccessResult::Unreachable));
+        }
+    }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn from_status_code() {
+        let r = AccessResult::from_status_code(http::StatusCode::NOT_FOUND, BODY);
+        assert!(matches!(r, AccessResult::Unavailable));
+    }
+
+    #[test]
+    fn over_limit() {
+        let r = AccessResult::after_redirects(6, 200, BODY);
+        assert!(matches!(r, AccessResult::Redirect));
+
+        let r = AccessResult::after_redirects(6, 503, BODY);
+        assert!(matches!(r, AccessResult::Redirect));
+    }
+}
@@ -0,0 +1,443 @@
+use std::fmt;
+use std::ops::Range;
+
+use crate::parse::inner::RobotsInner;
+use crate::parse::lexer::{Directive as Token, Lexer};
+
+/// The owned `robots.txt` directive, see [`Document`].
+///
+/// Values are trimmed and the empty `Disallow` is represented with the
+/// `Allow: /`, same as by the [`Robots`] parser.
+///
+/// Values with invalid UTF-8 are converted lossily. The parser drops such
+/// rules and sitemaps, so they're represented with the empty `Allow` (that
+/// is ignored, but still ends the user-agent list) and as unknown lines.
+///
+/// [`Robots`]: crate::Robots
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Directive {
+    UserAgent(String),
+    Allow(String),
+    Disallow(String),
+    CrawlDelay(String),
+    RequestRate(String),
+    VisitTime(String),
+    Sitemap(String),
+    /// Unrecognized directives, comments, empty lines and dropped values.
+    Unknown(String),
+}
+
+impl Directive {
+    /// Creates a new directive from the lexer token.
+    fn from_token(token: &Token) -> Self {
+        let owned = |x: &[u8]| String::from_utf8_lossy(x).into_owned();
+        let invalid = std::str::from_utf8(token.value()).is_err();
+        match *token {
+            // Dropped by the parser, see `Parser::try_rule`.
+            Token::Allow(_) | Token::Disallow(_) if invalid => Self::Allow(String::new()),
+            Token::Sitemap(x) if invalid => Self::Unknown(owned(x)),
+            Token::UserAgent(x) => Self::UserAgent(owned(x)),
+            Token::Allow(x) => Self::Allow(owned(x)),
+            Token::Disallow(x) => Self::Disallow(owned(x)),
+            Token::CrawlDelay(x) => Self::CrawlDelay(owned(x)),
+            Token::RequestRate(x) => Self::RequestRate(owned(x)),
+            Token::VisitTime(x) => Self::VisitTime(owned(x)),
+            Token::Sitemap(x) => Self::Sitemap(owned(x)),
+            Token::Unknown(x) => Self::Unknown(owned(x)),
+        }
+    }
+
+    /// Returns the borrowed lexer token of the directive.
+    pub(crate) fn as_token(&self) -> Token<'_> {
+        match self {
+            Self::UserAgent(x) => Token::UserAgent(x.as_bytes()),
+            Self::Allow(x) => Token::Allow(x.as_bytes()),
+            Self::Disallow(x) => Token::Disallow(x.as_bytes()),
+            Self::CrawlDelay(x) => Token::CrawlDelay(x.as_bytes()),
+            Self::RequestRate(x) => Token::RequestRate(x.as_bytes()),
+            Self::VisitTime(x) => Token::VisitTime(x.as_bytes()),
+            Self::Sitemap(x) => Token::Sitemap(x.as_bytes()),
+            Self::Unknown(x) => Token::Unknown(x.as_bytes()),
+        }
+    }
+
+    /// Returns true if the directive belongs to the group.
+    fn is_rule(&self) -> bool {
+        matches!(
+            self,
+            Self::Allow(_)
+                | Self::Disallow(_)
+                | Self::CrawlDelay(_)
+                | Self::RequestRate(_)
+                | Self::VisitTime(_)
+        )
+    }
+}
+
+impl fmt::Display for Directive {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UserAgent(x) => write!(f, "User-Agent: {x}"),
+            Self::Allow(x) => write!(f, "Allow: {x}"),
+            Self::Disallow(x) => write!(f, "Disallow: {x}"),
+            Self::CrawlDelay(x) => write!(f, "Crawl-Delay: {x}"),
+            Self::RequestRate(x) => write!(f, "Request-Rate: {x}"),
+            Self::VisitTime(x) => write!(f, "Visit-Time: {x}"),
+            Self::Sitemap(x) => write!(f, "Sitemap: {x}"),
+            Self::Unknown(x) => write!(f, "{x}"),
+        }
+    }
+}
+
+/// The single line of the [`Document`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Line {
+    directive: Directive,
+    /// The original bytes, unless inserted.
+    raw: Option<Vec<u8>>,
+    ending: &'static str,
+}
+
+/// The `robots.txt` file as the ordered list of all its directives,
+/// including unknown ones, comments and empty lines.
+///
+/// Unlike [`Robots`], that only keeps the rules of the matched user-agent,
+/// [`Document`] supports in-place edits: untouched lines (with their line
+/// endings) are rendered exactly as they were parsed, see [`Document::to_bytes`].
+///
+/// The input is prepared the same way as by [`Robots`], so directives match
+/// what it sees: the input is limited to [`BYTE_LIMIT`], UTF-16 is transcoded
+/// into UTF-8 and `\0` ends the line.
+///
+/// ```rust
+/// use robotxt::{Directive, Document};
+///
+/// let txt = "# Comment\nUser-Agent: foobot\nDisallow: /a # Inline\nNoindex: /b\n";
+/// let mut doc = Document::from_bytes(txt.as_bytes());
+///
+/// doc.insert_rule("foobot", Directive::Disallow("/c".to_string()));
+/// doc.remove_rule("foobot", &Directive::Disallow("/a".to_string()));
+///
+/// let exp = "# Comment\nUser-Agent: foobot\nDisallow: /c\nNoindex: /b\n";
+/// assert_eq!(doc.to_string(), exp);
+/// ```
+///
+/// [`Robots`]: crate::Robots
+/// [`BYTE_LIMIT`]: crate::BYTE_LIMIT
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Document {
+    lines: Vec<Line>,
+}
+
+impl Document {
+    /// Creates a new instance from the byte slice.
+    pub fn from_bytes(robots: &[u8]) -> Self {
+        // Replacing '\x00' keeps offsets, so both share line boundaries.
+        let decoded = RobotsInner::decode(robots);
+        let prepared = RobotsInner::prepare(robots);
+
+        // Every directive (including unknown or empty ones) takes exactly one line.
+        let mut tokens = Lexer::parse_tokens(&prepared).into_iter();
+        let mut lines = Vec::new();
+        let mut start = 0;
+
+        while start < prepared.len() {
+            let rest = &prepared[start..];
+            let (len, ending) = match rest.iter().position(|&u| u == b'\r' || u == b'\n') {
+                Some(i) if rest[i..].starts_with(b"\r\n") => (i, 2),
+                Some(i) => (i, 1),
+                None => (rest.len(), 0),
+            };
+
+            let end = start + len;
+            let ending = match &decoded[end..end + ending] {
+                b"\r\n" => "\r\n",
+                b"\r\0" => "\r\0",
+                b"\r" => "\r",
+                b"\n" => "\n",
+                b"\0" => "\0",
+                _ => "",
+            };
+
+            let token = tokens.next();
+            lines.push(Line {
+                directive: token.map_or(Directive::Unknown(String::new()), |u| {
+                    Directive::from_token(&u)
+                }),
+                raw: Some(decoded[start..end].to_vec()),
+                ending,
+            });
+
+            start = end + ending.len();
+        }
+
+        Self { lines }
+    }
+
+    /// Returns an iterator over all directives.
+    pub fn directives(&self) -> impl Iterator<Item = &Directive> {
+        self.lines.iter().map(|line| &line.directive)
+    }
+
+    /// Returns the total amount of lines.
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Returns true if there are no lines.
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Inserts the directive at the given line index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, directive: Directive) {
+        // Reuses the line ending style of the preceding line.
+        let ending = index.checked_sub(1).and_then(|i| self.lines.get(i));
+        let ending = ending.map_or("", |line| line.ending);
+        let ending = match ending.is_empty() || ending.contains('\0') {
+            true => "\n",
+            false => ending,
+        };
+
+        let raw = None;
+        let line = Line {
+            directive,
+            raw,
+            ending,
+        };
+        self.lines.insert(index, line);
+    }
+
+    /// Removes and returns the directive at the given line index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    pub fn remove(&mut self, index: usize) -> Directive {
+        self.lines.remove(index).directive
+    }
+
+    /// Appends the rule to the first group of the given user-agent, right
+    /// after its last rule. Appends the new group if there is none.
+    pub fn insert_rule(&mut self, user_agent: &str, directive: Directive) {
+        if let Some(group) = self.groups(user_agent).first() {
+            self.insert(group.end, directive);
+            return;
+        }
+
+        // Separates the new group with an empty line.
+        let separated = self.lines.last().is_none_or(
+            |line| matches!(&line.directive, Directive::Unknown(x) if x.trim().is_empty()),
+        );
+
+        if !separated {
+            self.insert(self.len(), Directive::Unknown(String::new()));
+        }
+
+        self.insert(self.len(), Directive::UserAgent(user_agent.to_string()));
+        self.insert(self.len(), directive);
+    }
+
+    /// Removes the rule from all groups of the given user-agent.
+    /// Returns true if any rule was removed.
+    pub fn remove_rule(&mut self, user_agent: &str, directive: &Directive) -> bool {
+        let mut indices: Vec<_> = (self.groups(user_agent).into_iter())
+            .flatten()
+            .filter(|&i| &self.lines[i].directive == directive)
+            .collect();
+
+        indices.sort_unstable();
+        for &index in indices.iter().rev() {
+            self.lines.remove(index);
+        }
+
+        !indices.is_empty()
+    }
+
+    /// Returns line ranges of the groups of the given user-agent, from the
+    /// first `User-Agent` line to the last rule (inclusive).
+    fn groups(&self, user_agent: &str) -> Vec<Range<usize>> {
+        let mut groups = Vec::new();
+        let mut current: Option<(Range<usize>, bool)> = None;
+        let mut captures = false;
+
+        for (index, line) in self.lines.iter().enumerate() {
+            match &line.directive {
+                Directive::UserAgent(ua) => {
+                    if !captures {
+                        groups.extend(current.take());
+                    }
+
+                    captures = true;
+                    let (range, matches) = current.get_or_insert((index..index, false));
+                    range.end = index + 1;
+                    *matches |= ua.eq_ignore_ascii_case(user_agent);
+                }
+                x if x.is_rule() => {
+                    captures = false;
+                    if let Some((range, _)) = current.as_mut() {
+                        range.end = index + 1;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        groups.extend(current);
+        let groups = groups.into_iter().filter(|(_, matches)| *matches);
+        groups.map(|(range, _)| range).collect()
+    }
+}
+
+impl Document {
+    /// Returns the rendered file as bytes. Unlike [`Document::to_string`],
+    /// keeps invalid UTF-8 of untouched lines as is.
+    ///
+    /// ```rust
+    /// use robotxt::Document;
+    ///
+    /// let txt = b"User-Agent: foobot\nDisallow: /\xff\x00Allow: /a\n";
+    /// assert_eq!(Document::from_bytes(txt).to_bytes(), txt);
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for (index, line) in self.lines.iter().enumerate() {
+            match &line.raw {
+                Some(raw) => bytes.extend_from_slice(raw),
+                None => bytes.extend_from_slice(line.directive.to_string().as_bytes()),
+            }
+
+            // The last original line may lack the ending.
+            let last = index + 1 == self.lines.len();
+            let ending = if line.ending.is_empty() && !last {
+                "\n"
+            } else {
+                line.ending
+            };
+
+            bytes.extend_from_slice(ending.as_bytes());
+        }
+
+        bytes
+    }
+}
+
+impl fmt::Display for Document {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", String::from_utf8_lossy(&self.to_bytes()))
+    }
+}
+
+#[cfg(test)]
+mod round_trip {
+    use super::*;
+
+    #[test]
+    fn unchanged() {
+        let txts = [
+            "",
+            "\n\n",
+            "User-Agent: foobot\nDisallow: /a",
+            "# Comment\r\nuser-agent:foobot # Inline\r\n\r\nNoindex: /b\rSitemap: https://example.com/1.xml\n",
+            "\u{feff}User-Agent: *\n  Disallow:   /a/   \nUnknown line\n",
+        ];
+
+        for txt in txts {
+            let doc = Document::from_bytes(txt.as_bytes());
+            assert_eq!(doc.to_string(), txt);
+        }
+    }
+
+    #[test]
+    fn unchanged_bytes() {
+        let txts: [&[u8]; 3] = [
+            b"User-Agent: *\nDisallow: /a\xff\nDisallow: /b\x00Disallow: /c\n",
+            b"User-Agent: *\r\x00Disallow: /\x00",
+            b"\xfe\n\xff",
+        ];
+
+        for txt in txts {
+            let doc = Document::from_bytes(txt);
+            assert_eq!(doc.to_bytes(), txt);
+        }
+    }
+
+    #[test]
+    fn prepared() {
+        let txt = b"User-Agent: *\nDisallow: /b\x00Disallow: /c";
+        let doc = Document::from_bytes(txt);
+        assert_eq!(doc.len(), 3);
+        assert!(doc
+            .directives()
+            .any(|u| u == &Directive::Disallow("/c".to_string())));
+
+        let txt = "User-Agent: *\nDisallow: /a";
+        let utf16: Vec<u8> = [0xFF, 0xFE]
+            .into_iter()
+            .chain(txt.encode_utf16().flat_map(u16::to_le_bytes))
+            .collect();
+
+        let doc = Document::from_bytes(&utf16);
+        assert_eq!(doc.to_string(), txt);
+        assert!(doc
+            .directives()
+            .any(|u| u == &Directive::Disallow("/a".to_string())));
+    }
+
+    #[test]
+    fn directives() {
+        let txt = "# Comment\nUser-Agent: foobot\nDisallow: /a # Inline\n\nNoindex: /b";
+        let doc = Document::from_bytes(txt.as_bytes());
+
+        let exp = [
+            Directive::Unknown("# Comment".to_string()),
+            Directive::UserAgent("foobot".to_string()),
+            Directive::Disallow("/a".to_string()),
+            Directive::Unknown(String::new()),
+            Directive::Unknown("Noindex: /b".to_string()),
+        ];
+
+        assert!(doc.directives().eq(exp.iter()));
+    }
+
+    #[test]
+    fn insert_rule() {
+        let txt = "User-Agent: foobot\r\nUser-Agent: barbot\r\nDisallow: /a\r\n# Comment\r\n";
+        let mut doc = Document::from_bytes(txt.as_bytes());
+        doc.insert_rule("BarBot", Directive::Allow("/a/b".to_string()));
+
+        let exp = "User-Agent: foobot\r\nUser-Agent: barbot\r\nDisallow: /a\r\nAllow: /a/b\r\n# Comment\r\n";
+        assert_eq!(doc.to_string(), exp);
+
+        doc.insert_rule("bazbot", Directive::Disallow("/".to_string()));
+        let exp = exp.to_string() + "\r\nUser-Agent: bazbot\r\nDisallow: /\r\n";
+        assert_eq!(doc.to_string(), exp);
+    }
+
+    #[test]
+    fn remove_rule() {
+        let txt =
+            "User-Agent: foobot\nDisallow: /a\nDisallow: /b\n\nUser-Agent: barbot\nDisallow: /a";
+        let mut doc = Document::from_bytes(txt.as_bytes());
+
+        assert!(doc.remove_rule("foobot", &Directive::Disallow("/a".to_string())));
+        assert!(!doc.remove_rule("foobot", &Directive::Disallow("/c".to_string())));
+
+        let exp = "User-Agent: foobot\nDisallow: /b\n\nUser-Agent: barbot\nDisallow: /a";
+        assert_eq!(doc.to_string(), exp);
+    }
+
+    #[test]
+    fn missing_ending() {
+        let mut doc = Document::from_bytes(b"User-Agent: foobot\nDisallow: /a");
+        doc.insert_rule("foobot", Directive::Allow("/a/b".to_string()));
+        assert_eq!(
+            doc.to_string(),
+            "User-Agent: foobot\nDisallow: /a\nAllow: /a/b\n"
+        );
+    }
+}
@@ -1,13 +1,21 @@
+use std::borrow::Cow;
 use std::cmp::min;
+use std::collections::HashMap;
 use std::time::Duration;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use time::Time;
 use url::Url;
 
+use crate::parse::document::Directive;
 use crate::parse::lexer::Lexer;
+use crate::parse::options::ParseOptions;
 use crate::parse::parser::Parser;
+use crate::parse::rate::RequestRate;
+use crate::parse::report::ParseReport;
 use crate::parse::rule::Rule;
+use crate::parse::span::Span;
 use crate::paths::normalize_path;
 use crate::BYTE_LIMIT;
 
@@ -19,50 +27,202 @@ pub(crate) enum Rules {
     Always(bool),
 }
 
+/// The [`NormalizedRules`] enum represents the applied rules independently
+/// of their order, see [`RobotsInner::normalized_rules`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum NormalizedRules<'a> {
+    Rules(Vec<(&'a str, bool)>),
+    Always(bool),
+}
+
 /// The [`RobotsInner`] struct provides convenient and efficient storage for
 /// the data associated with certain user-agent for further matching.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RobotsInner {
     user_agent: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    user_agent_original: String,
     #[cfg_attr(feature = "serde", serde(flatten))]
     rules: Rules,
     crawl_delay: Option<Duration>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    crawl_delay_errors: usize,
+    #[cfg_attr(feature = "serde", serde(default))]
+    request_rate: Option<RequestRate>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    visit_time: Option<(Time, Time)>,
     sitemaps: Vec<Url>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    sitemap_spans: Vec<Span>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    options: ParseOptions,
+    #[cfg_attr(feature = "serde", serde(default))]
+    source: Option<Vec<u8>>,
 }
 
 impl RobotsInner {
     /// Creates a new [`RobotsInner`] from the byte slice.
     pub fn from_bytes(robots: &[u8], user_agent: &str) -> Self {
-        // Limits the input to 500 kibibytes.
-        let limit = min(robots.len(), BYTE_LIMIT);
-        let robots = &robots[0..limit];
+        Self::from_bytes_with(robots, user_agent, ParseOptions::default())
+    }
 
-        // Replaces '\x00' with '\n'.
-        let robots: Vec<_> = robots
-            .iter()
-            .map(|u| match u {
-                b'\x00' => b'\n',
-                v => *v,
-            })
-            .collect();
+    /// Creates a new [`RobotsInner`] from the byte slice with the given options.
+    pub fn from_bytes_with(robots: &[u8], user_agent: &str, options: ParseOptions) -> Self {
+        Self::from_bytes_with_base(robots, user_agent, options, None)
+    }
+
+    /// Creates a new [`RobotsInner`] from the byte slice with the given options,
+    /// resolving relative sitemaps against the base address, if provided.
+    pub fn from_bytes_with_base(
+        robots: &[u8],
+        user_agent: &str,
+        options: ParseOptions,
+        base: Option<&Url>,
+    ) -> Self {
+        let source = options
+            .retain_source
+            .then(|| Self::truncate(robots).to_vec());
+        let utf16 = Self::has_utf16_bom(robots);
+        let robots = Self::prepare(robots);
 
         let directives = Lexer::parse_tokens(&robots);
-        let state = Parser::parse_rules(&directives, user_agent);
+        let state = Parser::parse_rules(&directives, user_agent, base);
+
+        // Every directive takes exactly one line.
+        let span = |&index: &usize| {
+            let value = directives[index].value();
+            let span = Span::from_subslice(&robots, value, index + 1);
+            match utf16 {
+                true => span.map(|u| Self::utf16_offset(&robots, u)),
+                false => span,
+            }
+        };
+
+        let sitemap_spans = match options.track_positions {
+            true => state.sitemap_lines.iter().map(span).collect(),
+            false => Vec::default(),
+        };
+
+        Self {
+            user_agent: state.longest_match,
+            user_agent_original: state.longest_match_original,
+            rules: Self::optimize(state.rules, options.optimize),
+            crawl_delay: state.crawl_delay,
+            crawl_delay_errors: state.crawl_delay_errors,
+            request_rate: state.request_rate,
+            visit_time: state.visit_time,
+            sitemaps: state.sitemaps,
+            sitemap_spans,
+            options,
+            source,
+        }
+    }
+
+    /// Creates a new [`RobotsInner`] from already lexed directives.
+    pub fn from_directives(directives: &[Directive], user_agent: &str) -> Self {
+        let directives: Vec<_> = directives.iter().map(Directive::as_token).collect();
+        let state = Parser::parse_rules(&directives, user_agent, None);
+        let options = ParseOptions::default();
 
         Self {
             user_agent: state.longest_match,
-            rules: Self::optimize(state.rules),
+            user_agent_original: state.longest_match_original,
+            rules: Self::optimize(state.rules, options.optimize),
             crawl_delay: state.crawl_delay,
+            crawl_delay_errors: state.crawl_delay_errors,
+            request_rate: state.request_rate,
+            visit_time: state.visit_time,
             sitemaps: state.sitemaps,
+            sitemap_spans: Vec::default(),
+            options,
+            source: None,
         }
     }
 
+    /// Returns crawl-delays of all given user-agents, lexing the file once.
+    pub fn crawl_delays(robots: &[u8], user_agents: &[&str]) -> HashMap<String, Option<Duration>> {
+        let robots = Self::prepare(robots);
+        let directives = Lexer::parse_tokens(&robots);
+
+        let delays = user_agents.iter().map(|&user_agent| {
+            let delay = Parser::parse_crawl_delay(&directives, user_agent);
+            (user_agent.to_string(), delay)
+        });
+
+        delays.collect()
+    }
+
+    /// Returns the summary of dropped directives of all groups.
+    pub fn parse_report(robots: &[u8]) -> ParseReport {
+        let robots = Self::prepare(robots);
+        let directives = Lexer::parse_tokens(&robots);
+        Parser::parse_report(&directives)
+    }
+
+    /// Limits the input to 500 kibibytes.
+    fn truncate(robots: &[u8]) -> &[u8] {
+        let limit = min(robots.len(), BYTE_LIMIT);
+        &robots[0..limit]
+    }
+
+    /// Returns true if the input starts with the UTF-16 BOM.
+    fn has_utf16_bom(robots: &[u8]) -> bool {
+        matches!(robots, [0xFF, 0xFE, ..] | [0xFE, 0xFF, ..])
+    }
+
+    /// Maps the byte offset in the transcoded UTF-8 back into the UTF-16 input.
+    /// Every replacement character stands for the single unpaired surrogate.
+    fn utf16_offset(decoded: &[u8], offset: usize) -> usize {
+        let prefix = String::from_utf8_lossy(&decoded[..offset]);
+        let units: usize = prefix.chars().map(char::len_utf16).sum();
+        2 + units * 2
+    }
+
+    /// Transcodes UTF-16 (little or big endian, detected by the BOM) into UTF-8.
+    /// Returns `None` if there is no UTF-16 BOM.
+    fn decode_utf16(robots: &[u8]) -> Option<Vec<u8>> {
+        let from_bytes: fn([u8; 2]) -> u16 = match robots {
+            [0xFF, 0xFE, ..] => u16::from_le_bytes,
+            [0xFE, 0xFF, ..] => u16::from_be_bytes,
+            _ => return None,
+        };
+
+        let units = robots[2..].chunks_exact(2);
+        let units = units.map(|u| from_bytes([u[0], u[1]]));
+        let chars = char::decode_utf16(units).map(|u| u.unwrap_or(char::REPLACEMENT_CHARACTER));
+        Some(chars.collect::<String>().into_bytes())
+    }
+
+    /// Limits the input and transcodes UTF-16, keeping '\x00' as is.
+    pub(crate) fn decode(robots: &[u8]) -> Cow<'_, [u8]> {
+        let robots = Self::truncate(robots);
+        match Self::decode_utf16(robots) {
+            Some(decoded) => Cow::Owned(Self::truncate(&decoded).to_vec()),
+            None => Cow::Borrowed(robots),
+        }
+    }
+
+    /// Limits the input, transcodes UTF-16 and replaces '\x00' with '\n'.
+    pub(crate) fn prepare(robots: &[u8]) -> Vec<u8> {
+        let robots = Self::decode(robots);
+        robots
+            .iter()
+            .map(|u| match u {
+                b'\x00' => b'\n',
+                v => *v,
+            })
+            .collect()
+    }
+
     // Applies optimizations if enabled.
-    fn optimize(rules: Vec<Rule>) -> Rules {
+    fn optimize(rules: Vec<Rule>, enabled: bool) -> Rules {
         // TODO: Remove overlapping rules.
 
-        #[cfg(feature = "optimal")]
+        if !enabled {
+            return Rules::Rules(rules);
+        }
+
         if rules.is_empty() || rules.iter().all(|r| r.is_allowed()) {
             // Empty or all allow.
             return Rules::Always(true);
@@ -81,19 +241,46 @@ impl RobotsInner {
     pub fn from_always(always: bool, crawl_delay: Option<Duration>, user_agent: &str) -> Self {
         Self {
             user_agent: user_agent.to_string(),
+            user_agent_original: user_agent.to_string(),
             rules: Rules::Always(always),
             crawl_delay,
+            crawl_delay_errors: 0,
+            request_rate: None,
+            visit_time: None,
             sitemaps: Vec::default(),
+            sitemap_spans: Vec::default(),
+            options: ParseOptions::default(),
+            source: None,
+        }
+    }
+
+    /// Creates a new [`RobotsInner`] from the set of rules.
+    #[cfg(feature = "serde")]
+    pub fn from_rules(rules: Rules, user_agent: &str) -> Self {
+        Self {
+            user_agent: user_agent.to_string(),
+            user_agent_original: user_agent.to_string(),
+            rules,
+            crawl_delay: None,
+            crawl_delay_errors: 0,
+            request_rate: None,
+            visit_time: None,
+            sitemaps: Vec::default(),
+            sitemap_spans: Vec::default(),
+            options: ParseOptions::default(),
+            source: None,
         }
     }
 
     /// Returns `Some(true)` if there is an explicit `allow` or the global rule.
+    /// Returns `Some(false)` if the path exceeds the maximal length.
     /// NOTE: Expects relative path.
     pub fn try_is_allowed(&self, path: &str) -> Option<bool> {
         match self.rules {
             Rules::Always(always) => Some(always),
+            Rules::Rules(_) if path.len() > self.options.max_path_len => Some(false),
             Rules::Rules(ref rules) => match normalize_path(path).as_str() {
-                "/robots.txt" => Some(true),
+                "/robots.txt" if self.options.robots_txt_always_allowed => Some(true),
                 path => rules
                     .iter()
                     .find(|r| r.is_match(path))
@@ -102,6 +289,37 @@ impl RobotsInner {
         }
     }
 
+    /// Returns true if the path is matched by any rule, regardless of its kind.
+    /// NOTE: Expects relative path.
+    pub fn is_referenced(&self, path: &str) -> bool {
+        let Rules::Rules(ref rules) = self.rules else {
+            return false;
+        };
+
+        let path = normalize_path(path);
+        rules.iter().any(|r| r.is_match(path.as_str()))
+    }
+
+    /// Returns true if the path is matched by both `allow` and `disallow` rules
+    /// of the same specificity i.e. the precedence is resolved arbitrarily.
+    /// NOTE: Expects relative path.
+    pub fn is_conflicted(&self, path: &str) -> bool {
+        let Rules::Rules(ref rules) = self.rules else {
+            return false;
+        };
+
+        // Rules are sorted by length, so only the leading matches are relevant.
+        let path = normalize_path(path);
+        let mut matches = rules.iter().filter(|r| r.is_match(path.as_str()));
+        let Some(first) = matches.next() else {
+            return false;
+        };
+
+        matches
+            .take_while(|r| r.specificity() == first.specificity())
+            .any(|r| r.is_allowed() != first.is_allowed())
+    }
+
     /// Returns true if the relative path is allowed for this set of rules.
     /// NOTE: Expects relative path.
     pub fn is_allowed(&self, path: &str) -> bool {
@@ -117,21 +335,157 @@ impl RobotsInner {
         }
     }
 
+    /// Returns the applied set of rules.
+    #[cfg(feature = "serde")]
+    pub fn rules(&self) -> &Rules {
+        &self.rules
+    }
+
+    /// Returns patterns of the applied rules and whether they allow.
+    #[cfg(feature = "builder")]
+    pub fn patterns(&self) -> impl Iterator<Item = (&str, bool)> {
+        let rules = match &self.rules {
+            Rules::Rules(rules) => rules.as_slice(),
+            Rules::Always(_) => &[],
+        };
+
+        rules.iter().map(|r| (r.pattern(), r.is_allowed()))
+    }
+
+    /// Returns patterns of the applied rules with the given permission.
+    /// The global rule is represented with the single `/` pattern.
+    pub fn patterns_with(&self, allow: bool) -> Vec<&str> {
+        match &self.rules {
+            Rules::Rules(rules) => {
+                let rules = rules.iter().filter(|r| r.is_allowed() == allow);
+                rules.map(|r| r.pattern()).collect()
+            }
+            Rules::Always(always) if *always == allow => vec!["/"],
+            Rules::Always(_) => Vec::new(),
+        }
+    }
+
     /// Returns the longest matching user-agent.
     pub fn user_agent(&self) -> &str {
         self.user_agent.as_ref()
     }
 
+    /// Returns the longest matching user-agent as declared in the file.
+    /// Falls back to the normalized user-agent if missing e.g. deserialized
+    /// from the older format.
+    pub fn user_agent_original(&self) -> &str {
+        match self.user_agent_original.is_empty() {
+            true => self.user_agent.as_ref(),
+            false => self.user_agent_original.as_ref(),
+        }
+    }
+
     /// Returns the specified crawl-delay.
     pub fn crawl_delay(&self) -> Option<Duration> {
         self.crawl_delay
     }
 
+    /// Returns the retained source file, if enabled.
+    pub fn source(&self) -> Option<&[u8]> {
+        self.source.as_deref()
+    }
+
+    /// Returns the options used to parse the file.
+    pub fn options(&self) -> ParseOptions {
+        self.options
+    }
+
+    /// Returns the amount of `crawl-delay` directives of the matched group
+    /// that failed to parse.
+    pub fn crawl_delay_errors(&self) -> usize {
+        self.crawl_delay_errors
+    }
+
+    /// Returns the request-rate of the user-agent if specified.
+    pub fn request_rate(&self) -> Option<RequestRate> {
+        self.request_rate
+    }
+
+    /// Returns the visit-time window of the user-agent if specified.
+    pub fn visit_time(&self) -> Option<(Time, Time)> {
+        self.visit_time
+    }
+
     /// Returns all collected sitemaps.
     pub fn sitemaps(&self) -> &[Url] {
         self.sitemaps.as_slice()
     }
 
+    /// Returns positions of all collected sitemaps, if tracked.
+    pub fn sitemap_spans(&self) -> &[Span] {
+        self.sitemap_spans.as_slice()
+    }
+
+    /// Returns sorted and deduplicated patterns of the applied rules, or
+    /// the global rule if constructed with (or reducible to) one.
+    pub(crate) fn normalized_rules(&self) -> NormalizedRules<'_> {
+        let rules = match &self.rules {
+            Rules::Rules(rules) => rules,
+            Rules::Always(always) => return NormalizedRules::Always(*always),
+        };
+
+        if rules.iter().all(Rule::is_universal) {
+            // The most specific universal rule applies to every path.
+            let always = rules.iter().min().is_none_or(Rule::is_allowed);
+            return NormalizedRules::Always(always);
+        }
+
+        let mut rules: Vec<_> = rules
+            .iter()
+            .map(|r| (r.pattern(), r.is_allowed()))
+            .collect();
+        rules.sort_unstable();
+        rules.dedup();
+        NormalizedRules::Rules(rules)
+    }
+
+    /// Returns the hash of the applied rules, crawl-delay and sitemaps.
+    ///
+    /// Uses the FNV-1a hash function, so that the result is stable across
+    /// process runs, and ignores both the order of rules and sitemaps.
+    pub fn content_hash(&self) -> u64 {
+        let mut hasher = Fnv64::default();
+
+        match &self.rules {
+            Rules::Rules(rules) => {
+                let mut rules: Vec<_> = rules
+                    .iter()
+                    .map(|r| (r.pattern(), r.is_allowed()))
+                    .collect();
+                rules.sort_unstable();
+
+                hasher.write(&[0]);
+                for (pattern, allow) in rules {
+                    hasher.write(&[allow as u8]);
+                    hasher.write_str(pattern);
+                }
+            }
+            Rules::Always(always) => hasher.write(&[1, *always as u8]),
+        }
+
+        match self.crawl_delay {
+            Some(delay) => {
+                hasher.write(&[1]);
+                hasher.write(&delay.as_nanos().to_le_bytes());
+            }
+            None => hasher.write(&[0]),
+        }
+
+        let mut sitemaps: Vec<_> = self.sitemaps.iter().map(Url::as_str).collect();
+        sitemaps.sort_unstable();
+        sitemaps.dedup();
+        for sitemap in sitemaps {
+            hasher.write_str(sitemap);
+        }
+
+        hasher.finish()
+    }
+
     /// Returns the total amount of applied rules unless constructed
     /// with (or optimized to) the global rule.
     pub fn len(&self) -> Option<usize> {
@@ -148,6 +502,66 @@ impl RobotsInner {
     }
 }
 
+impl PartialEq for RobotsInner {
+    fn eq(&self, other: &Self) -> bool {
+        // Parse error counters are diagnostics, not a part of the data.
+        let Self {
+            user_agent,
+            user_agent_original,
+            rules,
+            crawl_delay,
+            crawl_delay_errors: _,
+            request_rate,
+            visit_time,
+            sitemaps,
+            sitemap_spans,
+            options,
+            source,
+        } = self;
+
+        user_agent == &other.user_agent
+            && user_agent_original == &other.user_agent_original
+            && rules == &other.rules
+            && crawl_delay == &other.crawl_delay
+            && request_rate == &other.request_rate
+            && visit_time == &other.visit_time
+            && sitemaps == &other.sitemaps
+            && sitemap_spans == &other.sitemap_spans
+            && options == &other.options
+            && source == &other.source
+    }
+}
+
+impl Eq for RobotsInner {}
+
+/// The 64-bit FNV-1a hasher with the fixed offset basis.
+struct Fnv64(u64);
+
+impl Default for Fnv64 {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Fnv64 {
+    /// Writes the length-prefixed string, so that adjacent ones never collide.
+    fn write_str(&mut self, data: &str) {
+        self.write(&(data.len() as u64).to_le_bytes());
+        self.write(data.as_bytes());
+    }
+
+    fn write(&mut self, data: &[u8]) {
+        for byte in data {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
 #[cfg(test)]
 #[cfg(feature = "optimal")]
 mod optimal_output {
@@ -238,6 +652,464 @@ mod precedence_rules {
     }
 }
 
+#[cfg(test)]
+mod optimal_options {
+    use super::*;
+    use crate::ALL_UAS;
+
+    #[test]
+    fn disabled() {
+        let options = ParseOptions {
+            optimize: false,
+            ..ParseOptions::default()
+        };
+
+        let t = b"Allow: / \n Allow: /foo";
+        let r = RobotsInner::from_bytes_with(t, ALL_UAS, options);
+        assert_eq!(r.is_always(), None);
+        assert_eq!(r.len(), Some(2));
+        assert!(r.is_allowed("/foo"));
+    }
+
+    #[test]
+    fn enabled() {
+        let options = ParseOptions {
+            optimize: true,
+            ..ParseOptions::default()
+        };
+
+        let t = b"Disallow: / \n Disallow: /foo";
+        let r = RobotsInner::from_bytes_with(t, ALL_UAS, options);
+        assert_eq!(r.is_always(), Some(false));
+    }
+}
+
+#[cfg(test)]
+mod empty_disallow {
+    use super::*;
+    use crate::ALL_UAS;
+
+    const TXT: &[u8] = b"User-Agent: foobot \n Disallow: \n\n \
+        User-Agent: barbot \n Disallow: /private/ \n\n \
+        User-Agent: * \n Disallow: /";
+
+    #[test]
+    fn group_local() {
+        let r = RobotsInner::from_bytes(TXT, "foobot");
+        assert!(r.is_allowed("/private/"));
+        assert!(r.is_allowed("/page"));
+
+        let r = RobotsInner::from_bytes(TXT, "barbot");
+        assert!(!r.is_allowed("/private/"));
+        assert!(r.is_allowed("/page"));
+
+        let r = RobotsInner::from_bytes(TXT, ALL_UAS);
+        assert!(!r.is_allowed("/page"));
+    }
+
+    #[test]
+    fn shared_group() {
+        let t = b"User-Agent: foobot \n User-Agent: barbot \n Disallow: \n Disallow: /private/";
+        let r = RobotsInner::from_bytes(t, "barbot");
+        assert!(!r.is_allowed("/private/"));
+        assert!(r.is_allowed("/page"));
+    }
+}
+
+#[cfg(test)]
+mod empty_allow {
+    use super::*;
+
+    #[test]
+    fn ignored() {
+        let t = b"User-Agent: foobot \n Allow: \n Disallow: /private/";
+        let r = RobotsInner::from_bytes(t, "foobot");
+        assert!(!r.is_allowed("/private/"));
+        assert!(r.is_allowed("/page"));
+        assert_eq!(r.patterns_with(true), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn ends_group() {
+        let t = b"User-Agent: foobot \n Allow: \n User-Agent: barbot \n Disallow: /private/";
+        let r = RobotsInner::from_bytes(t, "foobot");
+        assert!(r.is_allowed("/private/"));
+
+        let r = RobotsInner::from_bytes(t, "barbot");
+        assert!(!r.is_allowed("/private/"));
+    }
+}
+
+#[cfg(test)]
+mod utf16 {
+    use super::*;
+
+    const TXT: &str =
+        "User-Agent: foobot\r\nDisallow: /private/\r\nSitemap: https://example.com/1.xml";
+
+    fn check(robots: &[u8]) {
+        let r = RobotsInner::from_bytes(robots, "foobot");
+        assert_eq!(r.user_agent(), "foobot");
+        assert!(!r.is_allowed("/private/"));
+        assert!(r.is_allowed("/page"));
+        assert_eq!(r.sitemaps().len(), 1);
+    }
+
+    #[test]
+    fn little_endian() {
+        let units = TXT.encode_utf16().flat_map(u16::to_le_bytes);
+        let robots: Vec<_> = [0xFF, 0xFE].into_iter().chain(units).collect();
+        check(&robots);
+    }
+
+    #[test]
+    fn big_endian() {
+        let units = TXT.encode_utf16().flat_map(u16::to_be_bytes);
+        let robots: Vec<_> = [0xFE, 0xFF].into_iter().chain(units).collect();
+        check(&robots);
+    }
+
+    #[test]
+    fn without_bom() {
+        let units: Vec<_> = TXT.encode_utf16().flat_map(u16::to_le_bytes).collect();
+        let r = RobotsInner::from_bytes(&units, "foobot");
+        assert!(r.is_allowed("/private/"));
+    }
+}
+
+#[cfg(test)]
+mod robots_txt {
+    use super::*;
+    use crate::ALL_UAS;
+
+    #[test]
+    fn always_allowed() {
+        let t = b"Disallow: /robots.txt \n Disallow: /page";
+        let r = RobotsInner::from_bytes(t, ALL_UAS);
+        assert!(r.is_allowed("/robots.txt"));
+        assert!(!r.is_allowed("/page"));
+    }
+
+    #[test]
+    fn literal() {
+        let options = ParseOptions {
+            robots_txt_always_allowed: false,
+            ..ParseOptions::default()
+        };
+
+        let t = b"Disallow: /robots.txt \n Disallow: /page";
+        let r = RobotsInner::from_bytes_with(t, ALL_UAS, options);
+        assert!(!r.is_allowed("/robots.txt"));
+        assert!(!r.is_allowed("/page"));
+    }
+}
+
+#[cfg(test)]
+mod path_len {
+    use super::*;
+    use crate::{ALL_UAS, PATH_LEN_LIMIT};
+
+    #[test]
+    fn over_limit() {
+        let t = b"Disallow: /*.php$ \n Allow: /";
+        let r = RobotsInner::from_bytes(t, ALL_UAS);
+        let path = "/".repeat(PATH_LEN_LIMIT + 1);
+        assert_eq!(r.try_is_allowed(&path), Some(false));
+        assert!(r.is_allowed(&path[..PATH_LEN_LIMIT]));
+    }
+
+    #[test]
+    fn custom_limit() {
+        let options = ParseOptions {
+            max_path_len: 8,
+            ..ParseOptions::default()
+        };
+
+        let t = b"Disallow: /private/";
+        let r = RobotsInner::from_bytes_with(t, ALL_UAS, options);
+        assert!(r.is_allowed("/1234567"));
+        assert!(!r.is_allowed("/12345678"));
+    }
+}
+
+#[cfg(test)]
+mod conflicts {
+    use super::*;
+    use crate::ALL_UAS;
+
+    #[test]
+    fn equal() {
+        let t = b"Allow: /x \n Disallow: /x";
+        let r = RobotsInner::from_bytes(t, ALL_UAS);
+        assert!(r.is_conflicted("/x"));
+        assert!(r.is_conflicted("/x/page"));
+        assert!(!r.is_conflicted("/y"));
+    }
+
+    #[test]
+    fn different() {
+        let t = b"Allow: /x/ \n Disallow: /x";
+        let r = RobotsInner::from_bytes(t, ALL_UAS);
+        assert!(!r.is_conflicted("/x/page"));
+    }
+}
+
+#[cfg(test)]
+mod crawl_delay {
+    use super::*;
+
+    #[test]
+    fn parse_errors() {
+        let t = b"User-Agent: foobot \n Crawl-Delay: 1e300 \n Crawl-Delay: 5 \n \
+            Crawl-Delay: -1 \n User-Agent: barbot \n Crawl-Delay: soon";
+        let r = RobotsInner::from_bytes(t, "foobot");
+        assert_eq!(r.crawl_delay(), None);
+        assert_eq!(r.crawl_delay_errors(), 2);
+
+        let t = b"User-Agent: foobot \n Crawl-Delay: 0.5";
+        let r = RobotsInner::from_bytes(t, "foobot");
+        assert_eq!(r.crawl_delay_errors(), 0);
+    }
+
+    #[test]
+    fn errors_not_compared() {
+        let t1 = b"User-Agent: foobot \n Crawl-Delay: soon \n Disallow: /";
+        let t2 = b"User-Agent: foobot \n Disallow: /";
+        let r1 = RobotsInner::from_bytes(t1, "foobot");
+        let r2 = RobotsInner::from_bytes(t2, "foobot");
+        assert_eq!(r1, r2);
+    }
+}
+
+#[cfg(test)]
+mod content_hash {
+    use super::*;
+
+    #[test]
+    fn agent_independent() {
+        let t = b"User-Agent: foobot \n Disallow: /private/ \n Allow: /public/ \n \
+            User-Agent: barbot \n Allow: /public/ \n Disallow: /private/";
+        let foo = RobotsInner::from_bytes(t, "foobot");
+        let bar = RobotsInner::from_bytes(t, "barbot");
+        assert_eq!(foo.content_hash(), bar.content_hash());
+
+        let t = b"User-Agent: foobot \n Disallow: /private/ \n Allow: /public/";
+        let other = RobotsInner::from_bytes(t, "foobot");
+        assert_eq!(foo.content_hash(), other.content_hash());
+    }
+
+    #[test]
+    fn content_dependent() {
+        let t = b"User-Agent: * \n Disallow: /private/";
+        let r = RobotsInner::from_bytes(t, "foobot");
+
+        let t = b"User-Agent: * \n Allow: /private/";
+        assert_ne!(
+            r.content_hash(),
+            RobotsInner::from_bytes(t, "foobot").content_hash()
+        );
+
+        let t = b"User-Agent: * \n Disallow: /private/ \n Crawl-Delay: 5";
+        assert_ne!(
+            r.content_hash(),
+            RobotsInner::from_bytes(t, "foobot").content_hash()
+        );
+
+        let t = b"User-Agent: * \n Disallow: /private/ \n Sitemap: https://example.com/1.xml";
+        assert_ne!(
+            r.content_hash(),
+            RobotsInner::from_bytes(t, "foobot").content_hash()
+        );
+    }
+
+    #[test]
+    fn stable() {
+        let r = RobotsInner::from_always(true, None, "foobot");
+        assert_eq!(r.content_hash(), 0xd0a6_fd18_672a_1435);
+    }
+}
+
+#[cfg(test)]
+mod sitemaps {
+    use super::*;
+    use crate::ALL_UAS;
+
+    const TXT: &[u8] = b"Sitemap: https://example.com/1.xml \n Sitemap: /2.xml \n \
+        Sitemap: https://example.com/1.xml \n Sitemap: 2.xml";
+
+    #[test]
+    fn deduplicated() {
+        let r = RobotsInner::from_bytes(TXT, ALL_UAS);
+        assert_eq!(r.sitemaps().len(), 1);
+        assert_eq!(r.sitemaps()[0].as_str(), "https://example.com/1.xml");
+    }
+
+    #[test]
+    fn resolved() {
+        let base = Url::parse("https://example.com/robots.txt").unwrap();
+        let options = ParseOptions {
+            track_positions: true,
+            ..ParseOptions::default()
+        };
+
+        let r = RobotsInner::from_bytes_with_base(TXT, ALL_UAS, options, Some(&base));
+        let sitemaps: Vec<_> = r.sitemaps().iter().map(Url::as_str).collect();
+        assert_eq!(
+            sitemaps,
+            ["https://example.com/1.xml", "https://example.com/2.xml"]
+        );
+
+        let lines: Vec<_> = r.sitemap_spans().iter().map(|u| u.line).collect();
+        assert_eq!(lines, [1, 2]);
+    }
+}
+
+#[cfg(test)]
+mod request_rate {
+    use super::*;
+
+    #[test]
+    fn per_agent() {
+        let t = b"User-Agent: foobot \n Request-rate: 1/10s \n Disallow: /a/ \n \
+            User-Agent: * \n Request-rate: 1/5 \n Request-rate: 1/1m \n Request-rate: 1/0";
+
+        let r = RobotsInner::from_bytes(t, "foobot");
+        let exp = RequestRate {
+            requests: 1,
+            per: Duration::from_secs(10),
+        };
+
+        assert_eq!(r.request_rate(), Some(exp));
+        assert!(!r.is_allowed("/a/"));
+
+        let r = RobotsInner::from_bytes(t, "barbot");
+        let exp = RequestRate {
+            requests: 1,
+            per: Duration::from_secs(60),
+        };
+
+        assert_eq!(r.request_rate(), Some(exp));
+    }
+
+    #[test]
+    fn malformed() {
+        let t = b"User-Agent: foobot \n Request-rate: fast \n Crawl-Delay: 5";
+        let r = RobotsInner::from_bytes(t, "foobot");
+        assert_eq!(r.request_rate(), None);
+        assert_eq!(r.crawl_delay(), Some(Duration::from_secs(5)));
+    }
+}
+
+#[cfg(test)]
+mod visit_time {
+    use time::macros::time;
+
+    use super::*;
+
+    #[test]
+    fn per_agent() {
+        let t = b"User-Agent: foobot \n Visit-time: 0600-0845 \n Visit-time: 1000-1200 \n \
+            Disallow: /a/ \n User-Agent: * \n Visit-time: 2500-0600";
+
+        let r = RobotsInner::from_bytes(t, "foobot");
+        assert_eq!(r.visit_time(), Some((time!(6:00), time!(8:45))));
+        assert!(!r.is_allowed("/a/"));
+        assert!(r.is_allowed("/b/"));
+
+        let r = RobotsInner::from_bytes(t, "barbot");
+        assert_eq!(r.visit_time(), None);
+    }
+}
+
+#[cfg(test)]
+mod crawl_delays {
+    use super::*;
+
+    #[test]
+    fn per_agent() {
+        let t = b"User-Agent: foobot \n Crawl-Delay: 5 \n \
+            User-Agent: barbot \n User-Agent: bazbot \n Crawl-Delay: 10 \n \
+            User-Agent: * \n Disallow: /private/";
+
+        let agents = ["foobot", "bazbot", "quxbot"];
+        let delays = RobotsInner::crawl_delays(t, &agents);
+        assert_eq!(delays.len(), 3);
+
+        for agent in agents {
+            let exp = RobotsInner::from_bytes(t, agent).crawl_delay();
+            assert_eq!(delays[agent], exp);
+        }
+
+        assert_eq!(delays["bazbot"], Some(Duration::from_secs(10)));
+        assert_eq!(delays["quxbot"], None);
+    }
+}
+
+#[cfg(test)]
+mod from_directives {
+    use super::*;
+    use crate::parse::document::Document;
+
+    #[test]
+    fn same_as_bytes() {
+        let t = b"User-Agent: foobot \n Crawl-Delay: 5 \n Disallow: /a/ \n \
+            User-Agent: * \n Disallow: /private/ \n Allow: /private/public/ \n \
+            Sitemap: https://example.com/1.xml";
+
+        let directives: Vec<_> = Document::from_bytes(t).directives().cloned().collect();
+        for agent in ["foobot", "barbot"] {
+            let r0 = RobotsInner::from_bytes(t, agent);
+            let r1 = RobotsInner::from_directives(&directives, agent);
+            assert_eq!(r0.user_agent(), r1.user_agent());
+            assert_eq!(r0.crawl_delay(), r1.crawl_delay());
+            assert_eq!(r0.sitemaps(), r1.sitemaps());
+            assert_eq!(r0.normalized_rules(), r1.normalized_rules());
+        }
+    }
+
+    #[test]
+    fn same_as_bytes_invalid() {
+        let ts: [&[u8]; 3] = [
+            b"User-Agent: *\nDisallow: /a\xff\nDisallow: /b\x00Disallow: /c\n",
+            b"User-Agent: foobot\nDisallow: /\xff\nUser-Agent: *\nDisallow: /",
+            b"User-Agent: foobot\nCrawl-Delay: 5\xff\nSitemap: https://example.com/\xff",
+        ];
+
+        for t in ts {
+            let directives: Vec<_> = Document::from_bytes(t).directives().cloned().collect();
+            for agent in ["foobot", "barbot"] {
+                let r0 = RobotsInner::from_bytes(t, agent);
+                let r1 = RobotsInner::from_directives(&directives, agent);
+                assert_eq!(r0.crawl_delay(), r1.crawl_delay());
+                assert_eq!(r0.sitemaps(), r1.sitemaps());
+                assert_eq!(r0.normalized_rules(), r1.normalized_rules());
+                assert_eq!(r0.is_allowed("/c"), r1.is_allowed("/c"));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod referenced {
+    use super::*;
+    use crate::ALL_UAS;
+
+    #[test]
+    fn rules() {
+        let t = b"Allow: /x/ \n Disallow: /x";
+        let r = RobotsInner::from_bytes(t, ALL_UAS);
+        assert!(r.is_referenced("/x/page") && r.is_allowed("/x/page"));
+        assert!(r.is_referenced("/xyz") && !r.is_allowed("/xyz"));
+        assert!(!r.is_referenced("/y") && r.is_allowed("/y"));
+    }
+
+    #[test]
+    fn always() {
+        let r = RobotsInner::from_bytes(b"", ALL_UAS);
+        assert!(!r.is_referenced("/x"));
+    }
+}
+
 #[cfg(test)]
 mod precedence_agents {
     use super::*;
@@ -292,6 +1164,32 @@ mod precedence_agents {
         assert!(!r.is_allowed("/3"));
     }
 
+    #[test]
+    fn equal_length() {
+        let t =
+            b"User-Agent: FooBot \n Allow: /1 \n Disallow: / \n User-Agent: foobot \n Allow: /2";
+        let r = RobotsInner::from_bytes(t, "foobot-search");
+        assert_eq!(r.user_agent(), "foobot");
+        assert_eq!(r.user_agent_original(), "FooBot");
+
+        // Both groups are merged.
+        assert!(r.is_allowed("/1"));
+        assert!(r.is_allowed("/2"));
+        assert!(!r.is_allowed("/3"));
+    }
+
+    #[test]
+    fn original_casing() {
+        let t = b"User-Agent: *  \n Disallow: / \n User-Agent:  FooBot-Search \n Allow: /";
+        let r = RobotsInner::from_bytes(t, "FOOBOT-search/1.0");
+        assert_eq!(r.user_agent(), "foobot-search");
+        assert_eq!(r.user_agent_original(), "FooBot-Search");
+        assert!(r.is_allowed("/page"));
+
+        let r = RobotsInner::from_bytes(t, "otherbot");
+        assert_eq!(r.user_agent_original(), "*");
+    }
+
     #[test]
     fn partial() {
         let r = RobotsInner::from_bytes(TXT, "bot-super");
@@ -304,3 +1202,80 @@ mod precedence_agents {
         assert!(!r.is_allowed("/2"));
     }
 }
+
+#[cfg(test)]
+mod positions {
+    use super::*;
+    use crate::ALL_UAS;
+
+    #[test]
+    fn sitemaps() {
+        let t = b"User-Agent: *\nDisallow: /\n\nSitemap:  https://example.com/1.xml \nSitemap: invalid\r\nsitemap: https://example.com/2.xml";
+        let options = ParseOptions {
+            track_positions: true,
+            ..ParseOptions::default()
+        };
+
+        let r = RobotsInner::from_bytes_with(t, ALL_UAS, options);
+        let spans = r.sitemap_spans();
+        assert_eq!(spans.len(), 2);
+
+        assert_eq!(spans[0].line, 4);
+        assert_eq!(
+            &t[spans[0].start..spans[0].end],
+            b"https://example.com/1.xml"
+        );
+        assert_eq!(spans[1].line, 6);
+        assert_eq!(
+            &t[spans[1].start..spans[1].end],
+            b"https://example.com/2.xml"
+        );
+    }
+
+    #[test]
+    fn mixed_endings() {
+        let t = b"User-Agent: *\r\nDisallow: /a/\rAllow: /a/b\n\rSitemap: https://example.com/1.xml\r\n";
+        let options = ParseOptions {
+            track_positions: true,
+            ..ParseOptions::default()
+        };
+
+        let r = RobotsInner::from_bytes_with(t, ALL_UAS, options);
+        assert!(!r.is_allowed("/a/c"));
+        assert!(r.is_allowed("/a/b"));
+
+        let spans = r.sitemap_spans();
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].line, 5);
+    }
+
+    #[test]
+    fn utf16() {
+        let t = "User-Agent: *\nSitemap: https://example.com/ä.xml";
+        let t: Vec<u8> = [0xFF, 0xFE]
+            .into_iter()
+            .chain(t.encode_utf16().flat_map(u16::to_le_bytes))
+            .collect();
+        let options = ParseOptions {
+            track_positions: true,
+            ..ParseOptions::default()
+        };
+
+        let r = RobotsInner::from_bytes_with(&t, ALL_UAS, options);
+        let spans = r.sitemap_spans();
+        assert_eq!(spans.len(), 1);
+
+        let units = t[spans[0].start..spans[0].end].chunks_exact(2);
+        let units = units.map(|u| u16::from_le_bytes([u[0], u[1]]));
+        let value = String::from_utf16(&units.collect::<Vec<_>>()).unwrap();
+        assert_eq!(value, "https://example.com/ä.xml");
+    }
+
+    #[test]
+    fn disabled() {
+        let t = b"Sitemap: https://example.com/1.xml";
+        let r = RobotsInner::from_bytes(t, ALL_UAS);
+        assert_eq!(r.sitemaps().len(), 1);
+        assert!(r.sitemap_spans().is_empty());
+    }
+}
@@ -18,6 +18,8 @@ pub enum Directive<'a> {
     Allow(&'a [u8]),
     Disallow(&'a [u8]),
     CrawlDelay(&'a [u8]),
+    RequestRate(&'a [u8]),
+    VisitTime(&'a [u8]),
     Sitemap(&'a [u8]),
     Unknown(&'a [u8]),
 }
@@ -29,6 +31,8 @@ impl fmt::Debug for Directive<'_> {
             Self::Allow(x) => ("Allow", x),
             Self::Disallow(x) => ("Disallow", x),
             Self::CrawlDelay(x) => ("Crawl-Delay", x),
+            Self::RequestRate(x) => ("Request-Rate", x),
+            Self::VisitTime(x) => ("Visit-Time", x),
             Self::Sitemap(x) => ("Sitemap", x),
             Self::Unknown(x) => ("Unknown", x),
         };
@@ -37,6 +41,22 @@ impl fmt::Debug for Directive<'_> {
     }
 }
 
+impl<'a> Directive<'a> {
+    /// Returns the value of the directive.
+    pub fn value(&self) -> &'a [u8] {
+        match *self {
+            Self::UserAgent(x) => x,
+            Self::Allow(x) => x,
+            Self::Disallow(x) => x,
+            Self::CrawlDelay(x) => x,
+            Self::RequestRate(x) => x,
+            Self::VisitTime(x) => x,
+            Self::Sitemap(x) => x,
+            Self::Unknown(x) => x,
+        }
+    }
+}
+
 const CARRIAGE: u8 = b'\r';
 const NEWLINE: u8 = b'\n';
 const COMMENT: u8 = b'#';
@@ -52,11 +72,12 @@ fn not_line_ending_or_comment(c: u8) -> bool {
     c != NEWLINE && c != CARRIAGE && c != COMMENT
 }
 
-/// Consumes every character until a newline.
+/// Consumes a single line ending: `\r\n`, a lone `\r` or a lone `\n`.
+///
+/// Styles may be interleaved within one file, every ending still takes
+/// exactly one line (so that line numbers stay accurate).
 fn consume_newline(input: &[u8]) -> NomResult<&[u8], Option<&[u8]>> {
-    let (input, _) = take_while(|i| i == CARRIAGE)(input)?;
-    let (input, output) = opt(tag(b"\n"))(input)?;
-    Ok((input, output))
+    opt(alt((tag(b"\r\n"), tag(b"\r"), tag(b"\n"))))(input)
 }
 
 #[derive(Debug)]
@@ -68,7 +89,7 @@ impl Lexer {
     /// # Safety
     ///
     /// Discards the possibility of any error as [`unknown`] consumes anything.
-    pub fn parse_tokens(input: &[u8]) -> Vec<Directive> {
+    pub fn parse_tokens(input: &[u8]) -> Vec<Directive<'_>> {
         match Self::lex(input) {
             Ok((_, directives)) => directives,
             Err(_) => unreachable!(), // Vec::default()
@@ -76,7 +97,7 @@ impl Lexer {
     }
 
     /// Parses the input slice into the list of directives.
-    fn lex(input: &[u8]) -> NomResult<&[u8], Vec<Directive>> {
+    fn lex(input: &[u8]) -> NomResult<&[u8], Vec<Directive<'_>>> {
         // Removes the byte order mark (BOM).
         let (input, _) = opt(tag(b"\xef"))(input)?;
         let (input, _) = opt(tag(b"\xbb"))(input)?;
@@ -88,6 +109,8 @@ impl Lexer {
             Self::allow,
             Self::disallow,
             Self::crawl_delay,
+            Self::request_rate,
+            Self::visit_time,
             Self::sitemap,
             Self::unknown,
         ));
@@ -98,7 +121,7 @@ impl Lexer {
     }
 
     /// Attempts to parse the `User-Agent` directive.
-    fn user_agent(input: &[u8]) -> NomResult<&[u8], Directive> {
+    fn user_agent(input: &[u8]) -> NomResult<&[u8], Directive<'_>> {
         let spellings = (
             tag_no_case("user-agent"),
             tag_no_case("user agent"),
@@ -110,7 +133,7 @@ impl Lexer {
     }
 
     /// Attempts to parse the `Allow` directive.
-    fn allow(input: &[u8]) -> NomResult<&[u8], Directive> {
+    fn allow(input: &[u8]) -> NomResult<&[u8], Directive<'_>> {
         let spellings = (
             tag_no_case("allow"),
             tag_no_case("alow"),
@@ -122,7 +145,7 @@ impl Lexer {
     }
 
     /// Attempts to parse the `Disallow` directive.
-    fn disallow(input: &[u8]) -> NomResult<&[u8], Directive> {
+    fn disallow(input: &[u8]) -> NomResult<&[u8], Directive<'_>> {
         let spellings = (
             tag_no_case("disallow"),
             tag_no_case("dissallow"),
@@ -143,7 +166,7 @@ impl Lexer {
     }
 
     /// Attempts to parse the `Crawl-Delay` directive.
-    fn crawl_delay(input: &[u8]) -> NomResult<&[u8], Directive> {
+    fn crawl_delay(input: &[u8]) -> NomResult<&[u8], Directive<'_>> {
         let spellings = (
             tag_no_case("crawl-delay"),
             tag_no_case("crawl delay"),
@@ -154,8 +177,32 @@ impl Lexer {
         Ok((input, Directive::CrawlDelay(delay)))
     }
 
+    /// Attempts to parse the `Request-rate` directive.
+    fn request_rate(input: &[u8]) -> NomResult<&[u8], Directive<'_>> {
+        let spellings = (
+            tag_no_case("request-rate"),
+            tag_no_case("request rate"),
+            tag_no_case("requestrate"),
+        );
+
+        let (input, rate) = Self::builder(input, spellings)?;
+        Ok((input, Directive::RequestRate(rate)))
+    }
+
+    /// Attempts to parse the `Visit-time` directive.
+    fn visit_time(input: &[u8]) -> NomResult<&[u8], Directive<'_>> {
+        let spellings = (
+            tag_no_case("visit-time"),
+            tag_no_case("visit time"),
+            tag_no_case("visittime"),
+        );
+
+        let (input, window) = Self::builder(input, spellings)?;
+        Ok((input, Directive::VisitTime(window)))
+    }
+
     /// Attempts to parse the `Sitemap` directive.
-    fn sitemap(input: &[u8]) -> NomResult<&[u8], Directive> {
+    fn sitemap(input: &[u8]) -> NomResult<&[u8], Directive<'_>> {
         let spellings = (
             tag_no_case("sitemap"),
             tag_no_case("site-map"),
@@ -167,7 +214,7 @@ impl Lexer {
     }
 
     /// Consumes the rest of the line as no directives were found here.
-    fn unknown(input: &[u8]) -> NomResult<&[u8], Directive> {
+    fn unknown(input: &[u8]) -> NomResult<&[u8], Directive<'_>> {
         let (input, unknown) = take_while(not_line_ending)(input)?;
         let (input, _) = consume_newline(input)?;
         Ok((input, Directive::Unknown(unknown)))
@@ -224,4 +271,30 @@ mod lexing {
         let em = Directive::Unknown(b"");
         assert_eq!(r, vec![em, ua, em, ua]);
     }
+
+    #[test]
+    fn carriage_endings() {
+        let r = b"User-Agent: a\rDisallow: /\r";
+        let r = Lexer::parse_tokens(r);
+
+        let exp = vec![Directive::UserAgent(b"a"), Directive::Disallow(b"/")];
+        assert_eq!(r, exp);
+    }
+
+    #[test]
+    fn mixed_endings() {
+        let r = b"user-agent: a\r\nallow: /b\rdisallow: /c\ncrawl-delay: 5\r\rsitemap: d\r\n";
+        let r = Lexer::parse_tokens(r);
+
+        let exp = vec![
+            Directive::UserAgent(b"a"),
+            Directive::Allow(b"/b"),
+            Directive::Disallow(b"/c"),
+            Directive::CrawlDelay(b"5"),
+            Directive::Unknown(b""),
+            Directive::Sitemap(b"d"),
+        ];
+
+        assert_eq!(r, exp);
+    }
 }
@@ -0,0 +1,271 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::time::Duration;
+
+use url::Url;
+
+use crate::parse::inner::RobotsInner;
+use crate::parse::lexer::{Directive, Lexer};
+use crate::parse::rule::Rule;
+use crate::BYTE_LIMIT;
+
+/// The kind of the [`Lint`] i.e. the violated part of the specification.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LintKind {
+    /// `Allow`, `Disallow` or `Crawl-Delay` before the first `User-Agent`.
+    RuleOutsideGroup,
+    /// The rule pattern could not be parsed e.g. misplaced `$` wildcard.
+    InvalidRule,
+    /// The `Crawl-Delay` value is not a valid non-negative number of seconds.
+    InvalidCrawlDelay,
+    /// The `Sitemap` value is not a valid `http` or `https` address.
+    InvalidSitemap,
+    /// The same `User-Agent` is declared in several groups.
+    DuplicateAgent,
+    /// The file exceeds the [`BYTE_LIMIT`], the rest is ignored.
+    Oversized,
+}
+
+impl fmt::Display for LintKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let inner = match self {
+            Self::RuleOutsideGroup => "rule-outside-group",
+            Self::InvalidRule => "invalid-rule",
+            Self::InvalidCrawlDelay => "invalid-crawl-delay",
+            Self::InvalidSitemap => "invalid-sitemap",
+            Self::DuplicateAgent => "duplicate-agent",
+            Self::Oversized => "oversized",
+        };
+
+        fmt::Display::fmt(inner, f)
+    }
+}
+
+/// The single specification violation found by [`lint`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lint {
+    /// The kind of the violation.
+    pub kind: LintKind,
+    /// The line number (1-based) of the violation.
+    pub line: usize,
+    /// The human-readable description of the violation.
+    pub message: String,
+}
+
+impl Lint {
+    fn new(kind: LintKind, line: usize, message: impl Into<String>) -> Self {
+        let message = message.into();
+        Self {
+            kind,
+            line,
+            message,
+        }
+    }
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} ({})", self.line, self.message, self.kind)
+    }
+}
+
+/// Validates the `robots.txt` file against the RFC 9309 and reports all
+/// found violations in the order of appearance.
+///
+/// ```rust
+/// use robotxt::{lint, LintKind};
+///
+/// let txt = r#"
+///     Disallow: /private/
+///     User-Agent: foobot
+///     Disallow: /a$b
+///     Crawl-Delay: soon
+///     Sitemap: ftp://example.com/sitemap.xml
+/// "#.as_bytes();
+///
+/// let lints: Vec<_> = lint(txt).into_iter().map(|u| (u.kind, u.line)).collect();
+/// assert_eq!(lints, vec![
+///     (LintKind::RuleOutsideGroup, 2),
+///     (LintKind::InvalidRule, 4),
+///     (LintKind::InvalidCrawlDelay, 5),
+///     (LintKind::InvalidSitemap, 6),
+/// ]);
+/// ```
+pub fn lint(robots: &[u8]) -> Vec<Lint> {
+    let mut lints = Vec::new();
+
+    // Prepares the input the same way as the parser does.
+    let oversized = robots.len() > BYTE_LIMIT;
+    let robots = RobotsInner::prepare(robots);
+
+    // Every directive (including unknown or empty ones) takes exactly one line.
+    let directives = Lexer::parse_tokens(&robots);
+
+    let mut group = None;
+    let mut captures_group = false;
+    let mut agents = HashMap::new();
+
+    for (line, directive) in directives.iter().enumerate() {
+        let line = line + 1;
+        let data = |data: &[u8]| String::from_utf8_lossy(data).into_owned();
+
+        match *directive {
+            Directive::UserAgent(ua) => {
+                if !captures_group {
+                    group = Some(group.map_or(0, |u: usize| u + 1));
+                }
+
+                captures_group = true;
+                let ua = data(ua).to_lowercase();
+                let group = group.expect("should be set");
+                let first = *agents.entry(ua.clone()).or_insert((group, line));
+                if first.0 != group {
+                    let message =
+                        format!("user-agent `{ua}` is already declared on line {}", first.1);
+                    lints.push(Lint::new(LintKind::DuplicateAgent, line, message));
+                }
+            }
+
+            Directive::Allow(rule) | Directive::Disallow(rule) => {
+                captures_group = false;
+                if group.is_none() {
+                    let message = "rule is not preceded by any user-agent";
+                    lints.push(Lint::new(LintKind::RuleOutsideGroup, line, message));
+                }
+
+                let allow = matches!(directive, Directive::Allow(_));
+                if let Err(err) = Rule::new(&data(rule), allow) {
+                    let message = format!("invalid rule `{}`: {err}", data(rule));
+                    lints.push(Lint::new(LintKind::InvalidRule, line, message));
+                }
+            }
+
+            Directive::CrawlDelay(delay) => {
+                captures_group = false;
+                if group.is_none() {
+                    let message = "crawl-delay is not preceded by any user-agent";
+                    lints.push(Lint::new(LintKind::RuleOutsideGroup, line, message));
+                }
+
+                let valid = data(delay)
+                    .parse::<f64>()
+                    .ok()
+                    .and_then(|secs| Duration::try_from_secs_f64(secs).ok());
+                if valid.is_none() {
+                    let message = format!("invalid crawl-delay `{}`", data(delay));
+                    lints.push(Lint::new(LintKind::InvalidCrawlDelay, line, message));
+                }
+            }
+
+            Directive::RequestRate(_) => {
+                captures_group = false;
+                if group.is_none() {
+                    let message = "request-rate is not preceded by any user-agent";
+                    lints.push(Lint::new(LintKind::RuleOutsideGroup, line, message));
+                }
+            }
+
+            Directive::VisitTime(_) => {
+                captures_group = false;
+                if group.is_none() {
+                    let message = "visit-time is not preceded by any user-agent";
+                    lints.push(Lint::new(LintKind::RuleOutsideGroup, line, message));
+                }
+            }
+
+            Directive::Sitemap(addr) => {
+                let valid = Url::parse(&data(addr))
+                    .ok()
+                    .filter(|u| u.scheme() == "http" || u.scheme() == "https");
+                if valid.is_none() {
+                    let message = format!("invalid sitemap `{}`", data(addr));
+                    lints.push(Lint::new(LintKind::InvalidSitemap, line, message));
+                }
+            }
+
+            Directive::Unknown(_) => {}
+        }
+    }
+
+    if oversized {
+        let message = format!("file exceeds {BYTE_LIMIT} bytes, the rest is ignored");
+        lints.push(Lint::new(LintKind::Oversized, directives.len(), message));
+    }
+
+    lints
+}
+
+#[cfg(test)]
+mod kinds {
+    use super::*;
+
+    fn kinds(robots: &[u8]) -> Vec<(LintKind, usize)> {
+        lint(robots).into_iter().map(|u| (u.kind, u.line)).collect()
+    }
+
+    #[test]
+    fn valid() {
+        let t = b"User-Agent: foobot \n Disallow: /a$ \n Crawl-Delay: 1.5 \n Sitemap: https://example.com/";
+        assert_eq!(kinds(t), vec![]);
+    }
+
+    #[test]
+    fn rule_outside_group() {
+        let t = b"Allow: /a \n Crawl-Delay: 5 \n User-Agent: foobot \n Disallow: /b";
+        let exp = vec![
+            (LintKind::RuleOutsideGroup, 1),
+            (LintKind::RuleOutsideGroup, 2),
+        ];
+        assert_eq!(kinds(t), exp);
+    }
+
+    #[test]
+    fn invalid_rule() {
+        let t = b"User-Agent: foobot \n Disallow: /a$b \n Allow: /a$$";
+        let exp = vec![(LintKind::InvalidRule, 2), (LintKind::InvalidRule, 3)];
+        assert_eq!(kinds(t), exp);
+    }
+
+    #[test]
+    fn invalid_crawl_delay() {
+        let t = b"User-Agent: foobot \n Crawl-Delay: soon \n Crawl-Delay: -1";
+        let exp = vec![
+            (LintKind::InvalidCrawlDelay, 2),
+            (LintKind::InvalidCrawlDelay, 3),
+        ];
+        assert_eq!(kinds(t), exp);
+    }
+
+    #[test]
+    fn invalid_sitemap() {
+        let t = b"Sitemap: ftp://example.com/ \n Sitemap: /sitemap.xml";
+        let exp = vec![(LintKind::InvalidSitemap, 1), (LintKind::InvalidSitemap, 2)];
+        assert_eq!(kinds(t), exp);
+    }
+
+    #[test]
+    fn duplicate_agent() {
+        let t = b"User-Agent: foobot \n User-Agent: barbot \n Allow: / \n User-Agent: FooBot \n Allow: /";
+        assert_eq!(kinds(t), vec![(LintKind::DuplicateAgent, 4)]);
+    }
+
+    #[test]
+    fn oversized() {
+        let mut t = b"User-Agent: foobot\n".to_vec();
+        t.resize(BYTE_LIMIT + 1, b'#');
+        assert_eq!(kinds(&t), vec![(LintKind::Oversized, 2)]);
+    }
+
+    #[test]
+    fn utf16() {
+        let t = "User-Agent: foobot\nDisallow: /a$b\nCrawl-Delay: soon";
+        let t: Vec<u8> = [0xFF, 0xFE]
+            .into_iter()
+            .chain(t.encode_utf16().flat_map(u16::to_le_bytes))
+            .collect();
+
+        let exp = vec![(LintKind::InvalidRule, 2), (LintKind::InvalidCrawlDelay, 3)];
+        assert_eq!(kinds(&t), exp);
+    }
+}
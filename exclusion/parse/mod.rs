@@ -1,22 +1,42 @@
+use std::collections::HashMap;
 use std::io::{BufReader, Read};
 use std::sync::Arc;
+use std::time::Duration;
 
 use url::Url;
 
 use crate::BYTE_LIMIT;
 pub use access::AccessResult;
+pub use document::{Directive, Document};
 use inner::RobotsInner;
+pub use lint::{lint, Lint, LintKind};
+pub use options::ParseOptions;
+pub use rate::RequestRate;
+pub use report::ParseReport;
+pub use rule::{Error as RuleError, Rule, WildcardKind};
+pub use ruleset::RuleSet;
+pub use span::Span;
 
 mod access;
+mod document;
 mod inner;
 mod lexer;
+mod lint;
+mod options;
 mod parser;
+mod rate;
+mod report;
 mod rule;
+mod ruleset;
+mod span;
+mod visit;
 
 #[cfg(feature = "serde")]
 use ::serde::{Deserialize, Serialize};
 #[cfg(feature = "serde")]
 mod serde;
+#[cfg(feature = "serde")]
+pub use self::serde::RulesSnapshot;
 
 /// All user agents group, used as a default for user-agents that don't have
 /// an explicitly defined matching group.
@@ -62,6 +82,15 @@ pub const ALL_UAS: &str = "*";
 /// assert!(!r.is_relative_allowed("/example/nope.txt"));
 /// assert!(!r.is_relative_allowed("/invalid/path.txt"));
 /// ```
+///
+/// Note that patterns are always matched as path prefixes, never as
+/// substrings, unless they contain the `*` wildcard: `Disallow: foo`
+/// disallows `/foo.html`, but not `/barfoo.html`.
+///
+/// Note that only the rules of the matched user-agent are kept, so checking
+/// the same file for another user-agent requires either parsing it again,
+/// or retaining the source with [`ParseOptions::retain_source`].
+/// See [`Robots::for_agent`].
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Robots {
@@ -94,6 +123,114 @@ impl Robots {
         }
     }
 
+    /// Creates a new instance from the byte slice with the given options.
+    ///
+    /// ```rust
+    /// use robotxt::{ParseOptions, Robots};
+    ///
+    /// let txt = r#"
+    ///     User-Agent: foobot
+    ///     Disallow: /robots.txt
+    /// "#.as_bytes();
+    ///
+    /// let r = Robots::from_bytes_with(txt, "foobot", ParseOptions::default());
+    /// assert!(r.is_relative_allowed("/robots.txt"));
+    ///
+    /// let options = ParseOptions {
+    ///     robots_txt_always_allowed: false,
+    ///     ..ParseOptions::default()
+    /// };
+    ///
+    /// let r = Robots::from_bytes_with(txt, "foobot", options);
+    /// assert!(!r.is_relative_allowed("/robots.txt"));
+    /// ```
+    pub fn from_bytes_with(robots: &[u8], user_agent: &str, options: ParseOptions) -> Self {
+        let inner = RobotsInner::from_bytes_with(robots, user_agent, options);
+        Self {
+            inner: Arc::new(inner),
+        }
+    }
+
+    /// Creates a new instance from the byte slice, resolving relative
+    /// sitemaps (e.g. `Sitemap: /sitemap.xml`) against the base address,
+    /// which are otherwise dropped.
+    ///
+    /// ```rust
+    /// use url::Url;
+    /// use robotxt::Robots;
+    ///
+    /// let txt = r#"
+    ///     Sitemap: /sitemap_1.xml
+    ///     Sitemap: https://example.com/sitemap_1.xml
+    ///     Sitemap: https://example.net/sitemap_2.xml
+    /// "#.as_bytes();
+    ///
+    /// let base = Url::parse("https://example.com/robots.txt").unwrap();
+    /// let r = Robots::from_bytes_with_base(txt, "foobot", &base);
+    /// assert_eq!(r.sitemaps().len(), 2);
+    /// assert_eq!(r.sitemaps()[0].as_str(), "https://example.com/sitemap_1.xml");
+    /// ```
+    pub fn from_bytes_with_base(robots: &[u8], user_agent: &str, base: &Url) -> Self {
+        let options = ParseOptions::default();
+        let inner = RobotsInner::from_bytes_with_base(robots, user_agent, options, Some(base));
+        Self {
+            inner: Arc::new(inner),
+        }
+    }
+
+    /// Creates a new instance from the byte slice, alongside with the summary
+    /// of directives (of all groups) that are silently dropped while parsing,
+    /// e.g. to explain why the file isn't honored. See [`ParseReport`].
+    ///
+    /// ```rust
+    /// use robotxt::Robots;
+    ///
+    /// let txt = b"User-Agent: foobot \n Disallow: /a$b \n Disallow: /\xff \n Sitemap: /sitemap.xml";
+    /// let (r, report) = Robots::parse_report(txt, "foobot");
+    /// assert!(r.is_relative_allowed("/a"));
+    ///
+    /// assert_eq!(report.invalid_rules, 1);
+    /// assert_eq!(report.invalid_utf8, 1);
+    /// assert_eq!(report.invalid_sitemaps, 1);
+    /// ```
+    pub fn parse_report(robots: &[u8], user_agent: &str) -> (Self, ParseReport) {
+        let report = RobotsInner::parse_report(robots);
+        (Self::from_bytes(robots, user_agent), report)
+    }
+
+    /// Creates a new instance from already lexed directives, e.g. of the
+    /// [`Document`], so the same list is reused for several user-agents.
+    ///
+    /// [`Directive`]s own their values and don't borrow the original input,
+    /// so the list may outlive it. Sitemap spans and the source are not
+    /// available, as there is no original input to refer to.
+    ///
+    /// ```rust
+    /// use robotxt::{Directive, Document, Robots};
+    ///
+    /// let txt = r#"
+    ///     User-Agent: foobot
+    ///     Disallow: /private/
+    ///     User-Agent: barbot
+    ///     Disallow: /
+    /// "#.as_bytes();
+    ///
+    /// let directives: Vec<Directive> = Document::from_bytes(txt).directives().cloned().collect();
+    ///
+    /// let r = Robots::from_directives(&directives, "foobot");
+    /// assert!(!r.is_relative_allowed("/private/"));
+    /// assert!(r.is_relative_allowed("/public/"));
+    ///
+    /// let r = Robots::from_directives(&directives, "barbot");
+    /// assert!(!r.is_relative_allowed("/public/"));
+    /// ```
+    pub fn from_directives(directives: &[Directive], user_agent: &str) -> Self {
+        let inner = RobotsInner::from_directives(directives, user_agent);
+        Self {
+            inner: Arc::new(inner),
+        }
+    }
+
     /// Creates a new instance from the generic reader.
     ///
     /// ```rust
@@ -114,7 +251,32 @@ impl Robots {
     /// assert!(!r.is_relative_allowed("/invalid/path.txt"));
     /// ```
     pub fn from_reader<R: Read>(reader: R, user_agent: &str) -> Result<Self, std::io::Error> {
-        let reader = reader.take(BYTE_LIMIT as u64);
+        Self::from_reader_with_limit(reader, user_agent, BYTE_LIMIT)
+    }
+
+    /// Creates a new instance from the generic reader, reading at most
+    /// `limit` bytes (which is capped by [`BYTE_LIMIT`]).
+    ///
+    /// The whole (capped) file is buffered before parsing, so the lower
+    /// limit reduces the memory usage per parse, but everything past the
+    /// limit is ignored (including the possibly truncated last line).
+    ///
+    /// ```rust
+    /// use robotxt::Robots;
+    ///
+    /// let reader = "User-Agent: foobot \n Disallow: /a \n Disallow: /b".as_bytes();
+    ///
+    /// let r = Robots::from_reader_with_limit(reader, "foobot", 36).unwrap();
+    /// assert!(!r.is_relative_allowed("/a"));
+    /// assert!(r.is_relative_allowed("/b"));
+    /// ```
+    pub fn from_reader_with_limit<R: Read>(
+        reader: R,
+        user_agent: &str,
+        limit: usize,
+    ) -> Result<Self, std::io::Error> {
+        let limit = limit.min(BYTE_LIMIT);
+        let reader = reader.take(limit as u64);
         let mut reader = BufReader::new(reader);
 
         let mut buffer = Vec::new();
@@ -166,6 +328,25 @@ impl Robots {
         }
     }
 
+    /// Creates a new instance from the compact set of rules.
+    /// See [`Robots::rules_only`].
+    ///
+    /// ```rust
+    /// use robotxt::Robots;
+    ///
+    /// let r0 = Robots::from_bytes(b"Disallow: /nope.txt", "foobot");
+    /// let r1 = Robots::from_rules_only(r0.rules_only(), "foobot");
+    /// assert!(!r1.is_relative_allowed("/nope.txt"));
+    /// ```
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn from_rules_only(rules: RulesSnapshot, user_agent: &str) -> Self {
+        let inner = RobotsInner::from_rules(rules.rules, user_agent);
+        Self {
+            inner: Arc::new(inner),
+        }
+    }
+
     /// Creates a new builder with default settings.
     /// See [`RobotsBuilder::new`].
     ///
@@ -195,8 +376,53 @@ impl Robots {
     /// assert_eq!(r.try_is_relative_allowed("/example/nope.txt"), Some(false));
     /// assert_eq!(r.try_is_relative_allowed("/invalid/path.txt"), None);
     /// ```
-    pub fn try_is_relative_allowed(&self, addr: &str) -> Option<bool> {
-        self.inner.try_is_allowed(addr)
+    pub fn try_is_relative_allowed(&self, addr: impl AsRef<str>) -> Option<bool> {
+        self.inner.try_is_allowed(addr.as_ref())
+    }
+
+    /// Returns `true` if the path is matched by any rule, regardless of
+    /// whether it allows or disallows, i.e. the site has a specific rule
+    /// about this path. Always `false` if the rules fully allow or disallow.
+    /// NOTE: Expects relative path.
+    ///
+    /// ```rust
+    /// use robotxt::Robots;
+    ///
+    /// let txt = r#"
+    ///     User-Agent: foobot
+    ///     Allow: /example/
+    ///     Disallow: /example/nope.txt
+    /// "#.as_bytes();
+    ///
+    /// let r = Robots::from_bytes(txt, "foobot");
+    /// assert!(r.is_path_referenced("/example/yeah.txt"));
+    /// assert!(r.is_path_referenced("/example/nope.txt"));
+    /// assert!(!r.is_path_referenced("/invalid/path.txt"));
+    /// ```
+    pub fn is_path_referenced(&self, addr: impl AsRef<str>) -> bool {
+        self.inner.is_referenced(addr.as_ref())
+    }
+
+    /// Returns `true` if the path is matched by both `allow` and `disallow`
+    /// rules of equal specificity i.e. the precedence is resolved arbitrarily.
+    /// NOTE: Expects relative path.
+    ///
+    /// ```rust
+    /// use robotxt::Robots;
+    ///
+    /// let txt = r#"
+    ///     User-Agent: foobot
+    ///     Allow: /example/
+    ///     Disallow: /example/
+    ///     Disallow: /example/nope.txt
+    /// "#.as_bytes();
+    ///
+    /// let r = Robots::from_bytes(txt, "foobot");
+    /// assert!(r.is_conflicted("/example/yeah.txt"));
+    /// assert!(!r.is_conflicted("/example/nope.txt"));
+    /// ```
+    pub fn is_conflicted(&self, addr: impl AsRef<str>) -> bool {
+        self.inner.is_conflicted(addr.as_ref())
     }
 
     /// Returns `true` if the path is allowed for the user-agent.
@@ -217,8 +443,8 @@ impl Robots {
     /// assert!(!r.is_relative_allowed("/example/nope.txt"));
     /// assert!(!r.is_relative_allowed("/invalid/path.txt"));
     /// ```
-    pub fn is_relative_allowed(&self, addr: &str) -> bool {
-        self.inner.is_allowed(addr)
+    pub fn is_relative_allowed(&self, addr: impl AsRef<str>) -> bool {
+        self.inner.is_allowed(addr.as_ref())
     }
 
     /// Returns `Some(true)` if there is an explicit `allow` or the global rule.
@@ -281,6 +507,54 @@ impl Robots {
         self.try_is_absolute_allowed(addr).unwrap_or(true)
     }
 
+    /// Returns `Some(true)` if there is an explicit `allow` or the global rule.
+    /// NOTE: Ignores different host.
+    ///
+    /// ```rust
+    /// use http::Uri;
+    /// use robotxt::Robots;
+    ///
+    /// let txt = r#"
+    ///     User-Agent: foobot
+    ///     Allow: /example/
+    ///     Disallow: /example/nope.txt
+    /// "#.as_bytes();
+    ///
+    /// let r = Robots::from_bytes(txt, "foobot");
+    /// let uri = Uri::from_static("https://example.com/example/nope.txt?q=1");
+    /// assert_eq!(r.try_is_uri_allowed(&uri), Some(false));
+    /// ```
+    #[cfg(feature = "http")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "http")))]
+    pub fn try_is_uri_allowed(&self, addr: &http::Uri) -> Option<bool> {
+        let relative = addr.path_and_query().map(|u| u.as_str());
+        self.inner.try_is_allowed(relative.unwrap_or("/"))
+    }
+
+    /// Returns true if the path is allowed for the user-agent.
+    /// NOTE: Ignores different host.
+    ///
+    /// ```rust
+    /// use http::Uri;
+    /// use robotxt::Robots;
+    ///
+    /// let txt = r#"
+    ///     User-Agent: foobot
+    ///     Disallow: *
+    ///     Allow: /example/
+    ///     Disallow: /example/nope.txt
+    /// "#.as_bytes();
+    ///
+    /// let r = Robots::from_bytes(txt, "foobot");
+    /// assert!(r.is_uri_allowed(&Uri::from_static("https://example.com/example/yeah.txt")));
+    /// assert!(!r.is_uri_allowed(&Uri::from_static("https://example.com/example/nope.txt")));
+    /// ```
+    #[cfg(feature = "http")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "http")))]
+    pub fn is_uri_allowed(&self, addr: &http::Uri) -> bool {
+        self.try_is_uri_allowed(addr).unwrap_or(true)
+    }
+
     /// Returns `Some(_)` if the site is fully allowed or disallowed.
     ///
     /// ```rust
@@ -314,6 +588,80 @@ impl Robots {
         self.inner.user_agent()
     }
 
+    /// Returns the longest matching user-agent as it appears in the file
+    /// i.e. with the original casing.
+    ///
+    /// ```rust
+    /// use robotxt::Robots;
+    ///
+    /// let txt = r#"
+    ///     User-Agent: FooBot
+    /// "#.as_bytes();
+    ///
+    /// let r = Robots::from_bytes(txt, "foobot-search");
+    /// assert_eq!(r.user_agent(), "foobot");
+    /// assert_eq!(r.user_agent_original(), "FooBot");
+    /// ```
+    pub fn user_agent_original(&self) -> &str {
+        self.inner.user_agent_original()
+    }
+
+    /// Returns `true` if the source file is retained i.e. it was parsed
+    /// with the [`ParseOptions::retain_source`] enabled.
+    pub fn source_retained(&self) -> bool {
+        self.inner.source().is_some()
+    }
+
+    /// Returns a new instance for another user-agent, re-parsed from
+    /// the retained source file with the same options, or `None` if
+    /// the source is not retained. See [`Robots::source_retained`].
+    ///
+    /// ```rust
+    /// use robotxt::{ParseOptions, Robots};
+    ///
+    /// let txt = r#"
+    ///     User-Agent: foobot
+    ///     Disallow: /example/
+    ///     User-Agent: barbot
+    ///     Disallow: /
+    /// "#.as_bytes();
+    ///
+    /// let options = ParseOptions { retain_source: true, ..Default::default() };
+    /// let r = Robots::from_bytes_with(txt, "foobot", options);
+    /// assert!(r.is_relative_allowed("/index.html"));
+    ///
+    /// let r = r.for_agent("barbot").unwrap();
+    /// assert!(!r.is_relative_allowed("/index.html"));
+    /// ```
+    pub fn for_agent(&self, user_agent: &str) -> Option<Self> {
+        let source = self.inner.source()?;
+        let options = self.inner.options();
+        Some(Self::from_bytes_with(source, user_agent, options))
+    }
+
+    /// Returns crawl-delays of all given user-agents (if specified), parsing
+    /// the file once instead of once per user-agent.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use robotxt::Robots;
+    ///
+    /// let txt = r#"
+    ///     User-Agent: foobot
+    ///     Crawl-Delay: 5
+    ///     User-Agent: barbot
+    ///     Crawl-Delay: 10
+    /// "#.as_bytes();
+    ///
+    /// let delays = Robots::crawl_delays(txt, &["foobot", "barbot", "bazbot"]);
+    /// assert_eq!(delays["foobot"], Some(Duration::from_secs(5)));
+    /// assert_eq!(delays["barbot"], Some(Duration::from_secs(10)));
+    /// assert_eq!(delays["bazbot"], None);
+    /// ```
+    pub fn crawl_delays(robots: &[u8], user_agents: &[&str]) -> HashMap<String, Option<Duration>> {
+        RobotsInner::crawl_delays(robots, user_agents)
+    }
+
     /// Returns the crawl-delay of the user-agent if specified.
     ///
     /// ```rust
@@ -332,6 +680,122 @@ impl Robots {
         self.inner.crawl_delay()
     }
 
+    /// Returns the request-rate of the user-agent if specified, with the
+    /// non-standard `Request-rate` directive. Malformed values are ignored,
+    /// the slowest rate is kept if there are several.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use robotxt::{RequestRate, Robots};
+    ///
+    /// let txt = r#"
+    ///     User-Agent: foobot
+    ///     Request-rate: 1/10s
+    /// "#.as_bytes();
+    ///
+    /// let r = Robots::from_bytes(txt, "foobot");
+    /// let exp = RequestRate { requests: 1, per: Duration::from_secs(10) };
+    /// assert_eq!(r.request_rate(), Some(exp));
+    /// ```
+    pub fn request_rate(&self) -> Option<RequestRate> {
+        self.inner.request_rate()
+    }
+
+    /// Returns the UTC window, during which crawling is preferred, of the
+    /// user-agent if specified with the non-standard `Visit-time` directive.
+    /// Malformed values are ignored, the first window is kept if there are
+    /// several. Doesn't affect [`Robots::is_relative_allowed`].
+    ///
+    /// ```rust
+    /// use robotxt::Robots;
+    /// use time::Time;
+    ///
+    /// let txt = r#"
+    ///     User-Agent: foobot
+    ///     Visit-time: 0600-0845
+    /// "#.as_bytes();
+    ///
+    /// let r = Robots::from_bytes(txt, "foobot");
+    /// let exp = (Time::from_hms(6, 0, 0).unwrap(), Time::from_hms(8, 45, 0).unwrap());
+    /// assert_eq!(r.visit_time(), Some(exp));
+    /// ```
+    pub fn visit_time(&self) -> Option<(time::Time, time::Time)> {
+        self.inner.visit_time()
+    }
+
+    /// Returns the crawl-delay of the user-agent in seconds if specified.
+    /// See [`Robots::crawl_delay`].
+    ///
+    /// ```rust
+    /// use robotxt::Robots;
+    ///
+    /// let txt = r#"
+    ///     User-Agent: foobot
+    ///     Crawl-Delay: 2.5
+    /// "#.as_bytes();
+    ///
+    /// let r = Robots::from_bytes(txt, "foobot");
+    /// assert_eq!(r.crawl_delay_secs(), Some(2.5));
+    /// ```
+    pub fn crawl_delay_secs(&self) -> Option<f64> {
+        self.crawl_delay().map(|d| d.as_secs_f64())
+    }
+
+    /// Returns the amount of `Crawl-Delay` directives in the matched group
+    /// that failed to parse e.g. negative, overflowing or non-numeric values.
+    /// Note that such a value also resets the previously parsed delay.
+    ///
+    /// ```rust
+    /// use robotxt::Robots;
+    ///
+    /// let txt = r#"
+    ///     User-Agent: foobot
+    ///     Crawl-Delay: 1e300
+    /// "#.as_bytes();
+    ///
+    /// let r = Robots::from_bytes(txt, "foobot");
+    /// assert_eq!(r.crawl_delay(), None);
+    /// assert_eq!(r.crawl_delay_parse_errors(), 1);
+    /// ```
+    pub fn crawl_delay_parse_errors(&self) -> usize {
+        self.inner.crawl_delay_errors()
+    }
+
+    /// Returns true if both instances apply the same rules, regardless of
+    /// their order, user-agents, crawl-delays and sitemaps. See [`RuleSet`].
+    ///
+    /// ```rust
+    /// use robotxt::Robots;
+    ///
+    /// let a = "User-Agent: foobot \n Disallow: /a/ \n Allow: /a/b".as_bytes();
+    /// let b = "User-Agent: * \n Allow: /a/b \n Disallow: /a/ \n Crawl-Delay: 5".as_bytes();
+    ///
+    /// let a = Robots::from_bytes(a, "foobot");
+    /// let b = Robots::from_bytes(b, "barbot");
+    /// assert!(a.rules_eq(&b));
+    /// ```
+    pub fn rules_eq(&self, other: &Self) -> bool {
+        self.inner.normalized_rules() == other.inner.normalized_rules()
+    }
+
+    /// Returns the hash of the applied rules, crawl-delay and sitemaps,
+    /// that is independent of the user-agent and stable across runs.
+    /// Useful to deduplicate identical policies of different hosts.
+    ///
+    /// ```rust
+    /// use robotxt::Robots;
+    ///
+    /// let a = "User-Agent: foobot \n Disallow: /private/".as_bytes();
+    /// let b = "User-Agent: * \n Disallow: /private/".as_bytes();
+    ///
+    /// let a = Robots::from_bytes(a, "foobot");
+    /// let b = Robots::from_bytes(b, "barbot");
+    /// assert_eq!(a.content_hash(), b.content_hash());
+    /// ```
+    pub fn content_hash(&self) -> u64 {
+        self.inner.content_hash()
+    }
+
     /// Returns all collected sitemaps.
     ///
     /// ```rust
@@ -349,6 +813,93 @@ impl Robots {
         self.inner.sitemaps()
     }
 
+    /// Returns all collected sitemaps with positions of their directives.
+    /// Empty unless [`ParseOptions::track_positions`] is enabled.
+    /// Byte offsets refer to the original input, see [`Span`].
+    ///
+    /// ```rust
+    /// use robotxt::{ParseOptions, Robots};
+    ///
+    /// let txt = "User-Agent: *\nSitemap: https://example.com/sitemap.xml";
+    /// let options = ParseOptions { track_positions: true, ..ParseOptions::default() };
+    ///
+    /// let r = Robots::from_bytes_with(txt.as_bytes(), "foobot", options);
+    /// let (sitemap, span) = &r.sitemaps_with_positions()[0];
+    /// assert_eq!(span.line, 2);
+    /// assert_eq!(&txt[span.start..span.end], sitemap.as_str());
+    /// ```
+    pub fn sitemaps_with_positions(&self) -> Vec<(Url, Span)> {
+        let sitemaps = self.inner.sitemaps().iter().cloned();
+        let spans = self.inner.sitemap_spans().iter().copied();
+        sitemaps.zip(spans).collect()
+    }
+
+    /// Returns the compact serializable view of the applied rules
+    /// without the user-agent, crawl-delay and sitemaps.
+    ///
+    /// ```rust
+    /// use robotxt::Robots;
+    ///
+    /// let r = Robots::from_bytes(b"Disallow: /nope.txt", "foobot");
+    /// let json = serde_json::to_string(&r.rules_only()).unwrap();
+    /// assert_eq!(json, r#"{"allow":[],"disallow":["/nope.txt"]}"#);
+    /// ```
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn rules_only(&self) -> RulesSnapshot {
+        let rules = self.inner.rules().clone();
+        RulesSnapshot { rules }
+    }
+
+    /// Returns raw patterns of the applied `disallow` rules, possibly
+    /// containing wildcards. Returns the single `/` pattern if everything
+    /// is disallowed, and nothing if everything is allowed.
+    ///
+    /// ```rust
+    /// use robotxt::Robots;
+    ///
+    /// let txt = r#"
+    ///     User-Agent: foobot
+    ///     Allow: /example/yeah.txt
+    ///     Disallow: /example/
+    ///     Disallow: /*.php$
+    /// "#.as_bytes();
+    ///
+    /// let r = Robots::from_bytes(txt, "foobot");
+    /// let mut patterns = r.disallowed_patterns();
+    /// patterns.sort();
+    /// assert_eq!(patterns, ["/*.php$", "/example/"]);
+    /// ```
+    pub fn disallowed_patterns(&self) -> Vec<&str> {
+        self.inner.patterns_with(false)
+    }
+
+    /// Returns raw patterns of the applied `allow` rules, possibly
+    /// containing wildcards. Returns the single `/` pattern if everything
+    /// is allowed, and nothing if everything is disallowed.
+    ///
+    /// ```rust
+    /// use robotxt::Robots;
+    ///
+    /// let txt = r#"
+    ///     User-Agent: foobot
+    ///     Allow: /example/yeah.txt
+    ///     Disallow: /example/
+    /// "#.as_bytes();
+    ///
+    /// let r = Robots::from_bytes(txt, "foobot");
+    /// assert_eq!(r.allowed_patterns(), ["/example/yeah.txt"]);
+    /// ```
+    pub fn allowed_patterns(&self) -> Vec<&str> {
+        self.inner.patterns_with(true)
+    }
+
+    /// Returns patterns of the applied rules and whether they allow.
+    #[cfg(feature = "builder")]
+    pub(crate) fn patterns(&self) -> impl Iterator<Item = (&str, bool)> {
+        self.inner.patterns()
+    }
+
     /// Returns the total amount of applied rules unless constructed
     /// with (or optimized to) the global rule.
     pub fn len(&self) -> Option<usize> {
@@ -361,3 +912,235 @@ impl Robots {
         self.inner.is_empty()
     }
 }
+
+/// Writes the canonical `robots.txt` with the matched user-agent group and
+/// all collected sitemaps. See [`RobotsBuilder::from_robots`].
+///
+/// Note that it reflects only the retained single-agent view, not the
+/// original file with all its groups.
+///
+/// ```rust
+/// use robotxt::Robots;
+///
+/// let txt = r#"
+///     User-Agent: foobot
+///     Crawl-Delay: 5
+///     Disallow: /example/
+///     User-Agent: barbot
+///     Disallow: /
+/// "#.as_bytes();
+///
+/// let r = Robots::from_bytes(txt, "foobot");
+/// let exp = "User-Agent: foobot\nCrawl-Delay: 5\nDisallow: /example/";
+/// assert_eq!(r.to_string(), exp);
+/// ```
+///
+/// [`RobotsBuilder::from_robots`]: crate::RobotsBuilder::from_robots
+#[cfg(feature = "builder")]
+#[cfg_attr(docsrs, doc(cfg(feature = "builder")))]
+impl std::fmt::Display for Robots {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", crate::RobotsBuilder::from_robots(self))
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "builder")]
+mod display {
+    use crate::Robots;
+
+    #[test]
+    fn round_trip() {
+        let txt = b"User-Agent: foobot \n Disallow: /private/ \n Allow: /private/a* \n \
+            Crawl-Delay: 2.5 \n Request-rate: 1/10s \n Visit-time: 0600-0845 \n \
+            User-Agent: barbot \n Disallow: / \n \
+            Sitemap: https://example.com/sitemap.xml";
+
+        let r0 = Robots::from_bytes(txt, "foobot");
+        let r1 = Robots::from_bytes(r0.to_string().as_bytes(), "foobot");
+        assert_eq!(r0, r1);
+        assert!(r1.request_rate().is_some());
+        assert!(r1.visit_time().is_some());
+        assert!(!r0.to_string().contains("barbot"));
+    }
+
+    #[test]
+    fn always() {
+        let r = Robots::from_always(false, "foobot");
+        assert_eq!(r.to_string(), "User-Agent: foobot\nDisallow: /");
+    }
+}
+
+#[cfg(test)]
+mod parse_report {
+    use crate::Robots;
+
+    #[test]
+    fn clean() {
+        let t =
+            b"User-Agent: foobot \n Disallow: /a/ \n Allow: \n Sitemap: https://example.com/1.xml";
+        let (_, report) = Robots::parse_report(t, "foobot");
+        assert!(report.is_clean());
+    }
+
+    #[test]
+    fn all_groups() {
+        let t = b"User-Agent: foobot \n Disallow: /a$b \n \
+            User-Agent: barbot \n Disallow: /a$b$ \n Allow: /\xff/ \n \
+            User-Agent: \xfe \n Sitemap: not a url";
+
+        let (r, report) = Robots::parse_report(t, "foobot");
+        assert_eq!(r, Robots::from_bytes(t, "foobot"));
+        assert_eq!(report.invalid_rules, 2);
+        assert_eq!(report.invalid_utf8, 2);
+        assert_eq!(report.invalid_sitemaps, 1);
+        assert!(!report.is_clean());
+    }
+}
+
+#[cfg(test)]
+mod reader_limit {
+    use crate::Robots;
+
+    const TXT: &[u8] = b"User-Agent: foobot \n Disallow: /private/";
+
+    #[test]
+    fn within_limit() -> Result<(), std::io::Error> {
+        let r = Robots::from_reader_with_limit(TXT, "foobot", TXT.len())?;
+        assert!(!r.is_relative_allowed("/private/"));
+        Ok(())
+    }
+
+    #[test]
+    fn over_limit() -> Result<(), std::io::Error> {
+        let r = Robots::from_reader_with_limit(TXT, "foobot", 20)?;
+        assert!(r.is_relative_allowed("/private/"));
+
+        let r = Robots::from_reader_with_limit(TXT, "foobot", 0)?;
+        assert!(r.is_relative_allowed("/private/"));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod patterns {
+    use crate::Robots;
+
+    #[test]
+    fn by_permission() {
+        let txt =
+            b"User-Agent: foobot \n Disallow: /private/ \n Allow: /private/a* \n Disallow: /tmp";
+        let r = Robots::from_bytes(txt, "foobot");
+
+        let mut disallowed = r.disallowed_patterns();
+        disallowed.sort();
+        assert_eq!(disallowed, ["/private/", "/tmp"]);
+        assert_eq!(r.allowed_patterns(), ["/private/a*"]);
+    }
+
+    #[test]
+    fn always() {
+        let r = Robots::from_always(false, "foobot");
+        assert_eq!(r.disallowed_patterns(), ["/"]);
+        assert!(r.allowed_patterns().is_empty());
+
+        let r = Robots::from_always(true, "foobot");
+        assert!(r.disallowed_patterns().is_empty());
+        assert_eq!(r.allowed_patterns(), ["/"]);
+    }
+
+    #[test]
+    fn mixed() {
+        let txt = b"User-Agent: foobot \n Disallow: /private/ \n Allow: /private/a* \n \
+            Disallow: /*.php$ \n Allow: /public/ \n User-Agent: barbot \n Disallow: /bar/";
+        let r = Robots::from_bytes(txt, "foobot");
+
+        let mut disallowed = r.disallowed_patterns();
+        disallowed.sort();
+        assert_eq!(disallowed, ["/*.php$", "/private/"]);
+
+        let mut allowed = r.allowed_patterns();
+        allowed.sort();
+        assert_eq!(allowed, ["/private/a*", "/public/"]);
+    }
+}
+
+#[cfg(test)]
+mod for_agent {
+    use crate::{ParseOptions, Robots};
+
+    const TXT: &[u8] =
+        b"User-Agent: foobot \n Disallow: /foo/ \n User-Agent: barbot \n Disallow: /bar/";
+
+    #[test]
+    fn retained() {
+        let options = ParseOptions {
+            retain_source: true,
+            ..Default::default()
+        };
+
+        let r0 = Robots::from_bytes_with(TXT, "foobot", options);
+        assert!(r0.source_retained());
+
+        let r1 = r0.for_agent("barbot").unwrap();
+        assert!(r1.source_retained());
+        assert!(r1.is_relative_allowed("/foo/"));
+        assert!(!r1.is_relative_allowed("/bar/"));
+        assert_eq!(r1.for_agent("foobot").unwrap(), r0);
+    }
+
+    #[test]
+    fn not_retained() {
+        let r = Robots::from_bytes(TXT, "foobot");
+        assert!(!r.source_retained());
+        assert!(r.for_agent("barbot").is_none());
+    }
+}
+
+#[cfg(test)]
+mod relative {
+    use std::borrow::Cow;
+
+    use crate::Robots;
+
+    #[test]
+    fn as_ref() {
+        let txt = b"User-Agent: foobot \n Disallow: /private/";
+        let r = Robots::from_bytes(txt, "foobot");
+
+        let path = "/private/a.txt".to_string();
+        assert!(!r.is_relative_allowed(&path));
+        assert!(!r.is_relative_allowed(path.clone()));
+        assert!(!r.is_relative_allowed(path.as_str()));
+        assert!(!r.is_relative_allowed(Cow::Borrowed("/private/b.txt")));
+        assert!(r.is_relative_allowed(Cow::<str>::Owned("/public/".into())));
+        assert_eq!(r.try_is_relative_allowed(path), Some(false));
+    }
+
+    #[test]
+    fn anchored() {
+        let txt = b"User-Agent: foobot \n Disallow: foo \n Allow: /";
+        let r = Robots::from_bytes(txt, "foobot");
+
+        assert!(!r.is_relative_allowed("/foo.html"));
+        assert!(r.is_relative_allowed("/barfoo.html"));
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "http")]
+mod http_uri {
+    use http::Uri;
+
+    use crate::Robots;
+
+    #[test]
+    fn query() {
+        let txt = b"User-Agent: foobot \n Disallow: /search?q=";
+        let r = Robots::from_bytes(txt, "foobot");
+
+        assert!(!r.is_uri_allowed(&Uri::from_static("https://example.com/search?q=rust")));
+        assert!(r.is_uri_allowed(&Uri::from_static("https://example.com/search")));
+        assert!(r.is_uri_allowed(&Uri::from_static("/search?p=2")));
+    }
+}
@@ -0,0 +1,60 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::PATH_LEN_LIMIT;
+
+/// The set of options that alter how the `robots.txt` file is parsed and matched.
+///
+/// See [`Robots::from_bytes_with`].
+///
+/// ```rust
+/// use robotxt::{ParseOptions, Robots};
+///
+/// let options = ParseOptions {
+///     robots_txt_always_allowed: false,
+///     ..ParseOptions::default()
+/// };
+///
+/// let r = Robots::from_bytes_with(b"Disallow: /robots.txt", "foobot", options);
+/// assert!(!r.is_relative_allowed("/robots.txt"));
+/// ```
+///
+/// [`Robots::from_bytes_with`]: crate::Robots::from_bytes_with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct ParseOptions {
+    /// Always allows `/robots.txt` regardless of the rules, as the file itself
+    /// must be fetchable. Enabled by default.
+    pub robots_txt_always_allowed: bool,
+    /// Collapses the rules into the global rule if they fully allow or disallow.
+    /// Enabled by default with the `optimal` feature.
+    pub optimize: bool,
+    /// Records positions of the `Sitemap` directives in the file.
+    /// See [`Robots::sitemaps_with_positions`]. Disabled by default.
+    ///
+    /// Byte offsets of UTF-16 files are mapped back to the original input.
+    ///
+    /// [`Robots::sitemaps_with_positions`]: crate::Robots::sitemaps_with_positions
+    pub track_positions: bool,
+    /// Disallows paths longer than the limit (in bytes) without matching them
+    /// against the rules. Defaults to the [`PATH_LEN_LIMIT`].
+    pub max_path_len: usize,
+    /// Retains the source file to re-parse it for other user-agents.
+    /// See [`Robots::for_agent`]. Disabled by default.
+    ///
+    /// [`Robots::for_agent`]: crate::Robots::for_agent
+    pub retain_source: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            robots_txt_always_allowed: true,
+            optimize: cfg!(feature = "optimal"),
+            track_positions: false,
+            max_path_len: PATH_LEN_LIMIT,
+            retain_source: false,
+        }
+    }
+}
@@ -1,9 +1,13 @@
 use std::time::Duration;
 
+use time::Time;
 use url::Url;
 
 use crate::parse::lexer::Directive;
+use crate::parse::rate::RequestRate;
+use crate::parse::report::ParseReport;
 use crate::parse::rule::Rule;
+use crate::parse::visit::parse_visit_time;
 use crate::ALL_UAS;
 
 #[derive(Debug, Default)]
@@ -12,27 +16,38 @@ pub struct Parser {
     captures_rules: bool,
 
     pub longest_match: String,
+    pub longest_match_original: String,
     pub rules: Vec<Rule>,
     pub crawl_delay: Option<Duration>,
+    pub crawl_delay_errors: usize,
+    pub request_rate: Option<RequestRate>,
+    pub visit_time: Option<(Time, Time)>,
     pub sitemaps: Vec<Url>,
+    pub sitemap_lines: Vec<usize>,
 }
 
 impl Parser {
     /// Creates a new [`Parser`] with all extracted data from the list of directives.
-    pub fn parse_rules(directives: &[Directive], user_agent: &str) -> Self {
-        let (longest_match, captures_rules) = Self::longest_match(directives, user_agent);
+    /// Relative sitemaps are resolved against the base address, if provided.
+    pub fn parse_rules(directives: &[Directive], user_agent: &str, base: Option<&Url>) -> Self {
+        let (longest_match, longest_match_original, captures_rules) =
+            Self::longest_match(directives, user_agent);
         let mut state = Self {
             longest_match,
+            longest_match_original,
             captures_rules,
             ..Self::default()
         };
 
-        directives.iter().for_each(|directive| match directive {
+        let directives = directives.iter().enumerate();
+        directives.for_each(|(index, directive)| match directive {
             Directive::UserAgent(data) => state.try_user_agent(data),
             Directive::Allow(data) => state.try_rule(data, true),
             Directive::Disallow(data) => state.try_rule(data, false),
             Directive::CrawlDelay(data) => state.try_delay(data),
-            Directive::Sitemap(data) => state.try_sitemap(data),
+            Directive::RequestRate(data) => state.try_request_rate(data),
+            Directive::VisitTime(data) => state.try_visit_time(data),
+            Directive::Sitemap(data) => state.try_sitemap(data, index, base),
             Directive::Unknown(_) => {}
         });
 
@@ -42,9 +57,59 @@ impl Parser {
         state
     }
 
-    /// Finds the longest matching user-agent and if the parser should check non-assigned rules
-    /// i.e. `Allow`/`Disallow`/`Crawl-Delay` before the first `User-Agent`.
-    fn longest_match(directives: &[Directive], user_agent: &str) -> (String, bool) {
+    /// Returns the crawl-delay of the matching group, skipping rules.
+    pub fn parse_crawl_delay(directives: &[Directive], user_agent: &str) -> Option<Duration> {
+        let (longest_match, _, captures_rules) = Self::longest_match(directives, user_agent);
+        let mut state = Self {
+            longest_match,
+            captures_rules,
+            ..Self::default()
+        };
+
+        directives.iter().for_each(|directive| match directive {
+            Directive::UserAgent(data) => state.try_user_agent(data),
+            Directive::Allow(_)
+            | Directive::Disallow(_)
+            | Directive::RequestRate(_)
+            | Directive::VisitTime(_) => state.captures_group = false,
+            Directive::CrawlDelay(data) => state.try_delay(data),
+            Directive::Sitemap(_) | Directive::Unknown(_) => {}
+        });
+
+        state.crawl_delay
+    }
+
+    /// Counts directives of all groups, that are dropped while parsing.
+    pub fn parse_report(directives: &[Directive]) -> ParseReport {
+        let mut report = ParseReport::default();
+        for directive in directives {
+            let data = match directive {
+                Directive::Unknown(_) => continue,
+                x => std::str::from_utf8(x.value()),
+            };
+
+            let Ok(data) = data else {
+                report.invalid_utf8 += 1;
+                continue;
+            };
+
+            match directive {
+                Directive::Allow(_) if data.trim().is_empty() => {}
+                Directive::Allow(_) if Rule::new(data, true).is_err() => report.invalid_rules += 1,
+                Directive::Disallow(_) if Rule::new(data, false).is_err() => {
+                    report.invalid_rules += 1
+                }
+                Directive::Sitemap(_) if Url::parse(data).is_err() => report.invalid_sitemaps += 1,
+                _ => {}
+            }
+        }
+
+        report
+    }
+
+    /// Finds the longest matching user-agent (both normalized and as declared) and if the parser
+    /// should check non-assigned rules i.e. `Allow`/`Disallow`/`Crawl-Delay` before the first `User-Agent`.
+    fn longest_match(directives: &[Directive], user_agent: &str) -> (String, String, bool) {
         // Collects all `User-Agent`s.
         let all_uas = directives.iter().filter_map(|ua2| match ua2 {
             Directive::UserAgent(ua2) => std::str::from_utf8(ua2).ok(),
@@ -54,17 +119,18 @@ impl Parser {
         // Filters out non-acceptable `User-Agent`s.
         let user_agent = user_agent.trim().to_lowercase();
         let acceptable_uas = all_uas
-            .map(|ua| ua.trim().to_lowercase())
-            .filter(|ua| user_agent.starts_with(ua.as_str()));
+            .map(|ua| (ua.trim().to_lowercase(), ua.trim()))
+            .filter(|(ua, _)| user_agent.starts_with(ua.as_str()));
 
         // Finds the longest `User-Agent` in the acceptable pool.
-        let selected_ua = acceptable_uas
-            .max_by(|lhs, rhs| lhs.len().cmp(&rhs.len()))
-            .unwrap_or(ALL_UAS.to_string());
+        // The first declared one wins if there are several of the same length.
+        let (selected_ua, original_ua) = acceptable_uas
+            .reduce(|lhs, rhs| if rhs.0.len() > lhs.0.len() { rhs } else { lhs })
+            .unwrap_or((ALL_UAS.to_string(), ALL_UAS));
 
         // Determines if it should check non-assigned rules.
         let check_non_assigned = selected_ua == ALL_UAS;
-        (selected_ua, check_non_assigned)
+        (selected_ua, original_ua.to_string(), check_non_assigned)
     }
 
     /// Attempts to parse and match the `User-Agent`.
@@ -82,9 +148,11 @@ impl Parser {
     }
 
     /// Attempts to parse and store the valid matching `Rule`.
+    /// Empty `Allow` is ignored (but still ends the user-agent list), as
+    /// otherwise it is normalized into `Allow: /` and allows everything.
     fn try_rule(&mut self, data: &[u8], allow: bool) {
         self.captures_group = false;
-        if !self.captures_rules {
+        if !self.captures_rules || (allow && data.trim_ascii().is_empty()) {
             return;
         }
 
@@ -96,6 +164,7 @@ impl Parser {
     }
 
     /// Attempts to parse and store the valid `Duration` as a `crawl-delay`.
+    /// Counts invalid values, which also reset the previous one.
     fn try_delay(&mut self, data: &[u8]) {
         self.captures_group = false;
         if !self.captures_rules {
@@ -103,19 +172,66 @@ impl Parser {
         }
 
         let data = String::from_utf8(data.to_vec()).ok();
-        self.crawl_delay = data
+        let delay = data
             .and_then(|data| data.parse::<f64>().ok())
-            .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+            .and_then(|secs| Duration::try_from_secs_f64(secs).ok());
+
+        if delay.is_none() {
+            self.crawl_delay_errors += 1;
+        }
+
+        self.crawl_delay = delay
             .map(|curr| (self.crawl_delay.unwrap_or(curr), curr))
             .map(|(prev, curr)| prev.min(curr));
     }
 
-    /// Attempts to parse and store the valid `Url` address as a `sitemap`.
-    fn try_sitemap(&mut self, data: &[u8]) {
-        let data = String::from_utf8(data.to_vec()).ok();
-        let addr = data.and_then(|data| Url::parse(data.as_str()).ok());
-        if let Some(addr) = addr {
-            self.sitemaps.push(addr);
+    /// Attempts to parse and store the valid `RequestRate`.
+    /// Keeps the slowest one if there are several.
+    fn try_request_rate(&mut self, data: &[u8]) {
+        self.captures_group = false;
+        if !self.captures_rules {
+            return;
+        }
+
+        let data = std::str::from_utf8(data).ok();
+        let Some(curr) = data.and_then(RequestRate::parse) else {
+            return;
+        };
+
+        let prev = self.request_rate.unwrap_or(curr);
+        self.request_rate = Some(if curr.is_slower(&prev) { curr } else { prev });
+    }
+
+    /// Attempts to parse and store the valid `Visit-time` window.
+    /// Keeps the first one if there are several.
+    fn try_visit_time(&mut self, data: &[u8]) {
+        self.captures_group = false;
+        if !self.captures_rules || self.visit_time.is_some() {
+            return;
+        }
+
+        let data = std::str::from_utf8(data).ok();
+        self.visit_time = data.and_then(parse_visit_time);
+    }
+
+    /// Attempts to parse (or resolve against the base) and store the valid
+    /// `Url` address as a `sitemap` and the index of its directive.
+    /// Duplicate addresses are skipped.
+    fn try_sitemap(&mut self, data: &[u8], index: usize, base: Option<&Url>) {
+        let Ok(data) = std::str::from_utf8(data) else {
+            return;
+        };
+
+        let addr = match base {
+            Some(base) => base.join(data),
+            None => Url::parse(data),
+        };
+
+        if let Ok(addr) = addr {
+            if !self.sitemaps.contains(&addr) {
+                self.sitemaps.push(addr);
+                self.sitemap_lines.push(index);
+            }
         }
     }
 }
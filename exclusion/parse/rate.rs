@@ -0,0 +1,99 @@
+use std::time::Duration;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The allowed amount of requests per the period of time, specified with the
+/// non-standard `Request-rate` directive e.g. `Request-rate: 1/10s`.
+///
+/// See [`Robots::request_rate`].
+///
+/// [`Robots::request_rate`]: crate::Robots::request_rate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RequestRate {
+    /// The amount of requests.
+    pub requests: u32,
+    /// The period of time.
+    pub per: Duration,
+}
+
+impl RequestRate {
+    /// Parses the `N/M` value with the optional time unit of the period:
+    /// `s` (default), `m`, `h` or `d`. Returns `None` if the value is
+    /// malformed or either number is zero.
+    pub(crate) fn parse(data: &str) -> Option<Self> {
+        let (requests, per) = data.trim().split_once('/')?;
+        let requests = requests.trim().parse().ok().filter(|&u| u > 0)?;
+
+        let per = per.trim();
+        let (per, unit) = match per.find(|c: char| !c.is_ascii_digit()) {
+            Some(pos) => per.split_at(pos),
+            None => (per, "s"),
+        };
+
+        let unit = match unit.to_ascii_lowercase().as_str() {
+            "s" => 1,
+            "m" => 60,
+            "h" => 60 * 60,
+            "d" => 24 * 60 * 60,
+            _ => return None,
+        };
+
+        let per = per.parse::<u64>().ok().filter(|&u| u > 0)?;
+        let per = Duration::from_secs(per.checked_mul(unit)?);
+        Some(Self { requests, per })
+    }
+
+    /// Returns true if the rate allows fewer requests per second.
+    pub(crate) fn is_slower(&self, other: &Self) -> bool {
+        let lhs = u128::from(self.requests) * other.per.as_nanos();
+        let rhs = u128::from(other.requests) * self.per.as_nanos();
+        lhs < rhs
+    }
+}
+
+#[cfg(test)]
+mod parsing {
+    use super::*;
+
+    #[test]
+    fn valid() {
+        let rate = |requests, secs| {
+            let per = Duration::from_secs(secs);
+            Some(RequestRate { requests, per })
+        };
+
+        assert_eq!(RequestRate::parse("1/10s"), rate(1, 10));
+        assert_eq!(RequestRate::parse(" 1 / 10 "), rate(1, 10));
+        assert_eq!(RequestRate::parse("3/5m"), rate(3, 300));
+        assert_eq!(RequestRate::parse("1/1H"), rate(1, 3600));
+        assert_eq!(RequestRate::parse("100/1d"), rate(100, 86400));
+    }
+
+    #[test]
+    fn malformed() {
+        for data in [
+            "",
+            "1",
+            "1/",
+            "/10s",
+            "0/10s",
+            "1/0s",
+            "1/10x",
+            "a/10s",
+            "1/10s 0600-0845",
+        ] {
+            assert_eq!(RequestRate::parse(data), None, "{data}");
+        }
+    }
+
+    #[test]
+    fn slower() {
+        let lhs = RequestRate::parse("1/10s").unwrap();
+        let rhs = RequestRate::parse("1/5s").unwrap();
+        assert!(lhs.is_slower(&rhs));
+        assert!(!rhs.is_slower(&lhs));
+        assert!(!lhs.is_slower(&lhs));
+    }
+}
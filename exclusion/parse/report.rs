@@ -0,0 +1,28 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The summary of directives dropped while parsing the `robots.txt` file.
+///
+/// Unlike the rules, counts cover the whole file i.e. all groups,
+/// not only the one of the matched user-agent.
+///
+/// See [`Robots::parse_report`].
+///
+/// [`Robots::parse_report`]: crate::Robots::parse_report
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ParseReport {
+    /// The amount of known directives with a non UTF-8 value.
+    pub invalid_utf8: usize,
+    /// The amount of `Allow` and `Disallow` directives with an invalid pattern.
+    pub invalid_rules: usize,
+    /// The amount of `Sitemap` directives with an invalid address.
+    pub invalid_sitemaps: usize,
+}
+
+impl ParseReport {
+    /// Returns true if no directives were dropped.
+    pub fn is_clean(&self) -> bool {
+        *self == Self::default()
+    }
+}
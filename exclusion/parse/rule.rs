@@ -1,7 +1,9 @@
+use std::borrow::Cow;
 use std::cmp::Ordering;
 use std::sync::OnceLock;
 
-use regex::{escape, Regex, RegexBuilder};
+use regex::{Regex, RegexBuilder};
+use regex_syntax::escape_into;
 
 use crate::paths::normalize_path;
 
@@ -20,7 +22,8 @@ pub enum Error {
 #[derive(Debug, Clone)]
 pub enum Wildcard {
     Ending(String),
-    Universal(String),
+    /// Segments of the pattern between `*`s, split once on creation.
+    Universal(Vec<String>),
     Both(Regex),
 }
 
@@ -30,7 +33,7 @@ impl Wildcard {
     /// NOTE: Expects normalized relative path.
     pub fn new(pattern: &str) -> Result<Option<Self>, Error> {
         let contains_universal = pattern.contains('*');
-        let endings_amount = pattern.chars().filter(|&c| c == '$').count();
+        let endings_amount = pattern.bytes().filter(|&c| c == b'$').count();
         let contains_ending = endings_amount > 0;
 
         // None.
@@ -43,7 +46,7 @@ impl Wildcard {
             x if x > 1 => return Err(Error::TooManyEndings(x)),
             x if x == 1 && pattern.ends_with('$') && !contains_universal => {
                 let pattern = pattern.strip_suffix('$').expect("should end with '$'");
-                return Ok(Some(Self::Ending(pattern.to_string())));
+                return Ok(Some(Self::Ending(pattern.to_owned())));
             }
             x if x == 1 && !pattern.ends_with('$') => {
                 let pos = pattern.find('$').expect("should contain '$'");
@@ -52,19 +55,34 @@ impl Wildcard {
             _ => {} // x if x == 0 || contains_universal
         }
 
+        // Borrows the pattern unless it contains repeated '*'.
         static STAR_KILLER: OnceLock<Regex> = OnceLock::new();
         let star_killer = STAR_KILLER.get_or_init(|| Regex::new(r"\*+").expect("should compile"));
-        let pattern = star_killer.replace_all(pattern, "*");
+        let pattern = match pattern.contains("**") {
+            true => star_killer.replace_all(pattern, "*"),
+            false => Cow::Borrowed(pattern),
+        };
 
         // Only '*'.
         if contains_universal && !contains_ending {
-            return Ok(Some(Self::Universal(pattern.to_string())));
+            let segments = pattern.split('*').map(str::to_owned).collect();
+            return Ok(Some(Self::Universal(segments)));
         }
 
         // Both '$' and '*'.
-        let regex = escape(&pattern).replace("\\*", ".*").replace("\\$", "$");
-        let regex = '^'.to_string() + &regex;
+        // Builds '^seg1.*seg2$' in place, escaping segments only.
+        let pattern = pattern.strip_suffix('$').expect("should end with '$'");
+        let mut regex = String::with_capacity(pattern.len() * 2 + 2);
+        regex.push('^');
+        for (i, segment) in pattern.split('*').enumerate() {
+            if i > 0 {
+                regex.push_str(".*");
+            }
+
+            escape_into(segment, &mut regex);
+        }
 
+        regex.push('$');
         let regex = RegexBuilder::new(&regex)
             .dfa_size_limit(42 * (1 << 10))
             .size_limit(42 * (1 << 10))
@@ -78,9 +96,9 @@ impl Wildcard {
         path == pattern
     }
 
-    /// Returns true if the path matches the universal pattern.
-    fn match_universal(pattern: &str, path: &str) -> bool {
-        let mut splits = pattern.split('*');
+    /// Returns true if the path matches the universal pattern segments.
+    fn match_universal(segments: &[String], path: &str) -> bool {
+        let mut splits = segments.iter();
         let mut pos = 0;
 
         // The first split is special as it doesn't start with '*'.
@@ -106,7 +124,7 @@ impl Wildcard {
     pub fn is_match(&self, path: &str) -> bool {
         match &self {
             Self::Ending(p) => Self::match_ending(p.as_str(), path),
-            Self::Universal(p) => Self::match_universal(p.as_str(), path),
+            Self::Universal(s) => Self::match_universal(s.as_slice(), path),
             Self::Both(r) => r.is_match(path),
         }
     }
@@ -133,7 +151,7 @@ mod wildcard {
     #[test]
     fn universal() -> Result<(), Error> {
         let wildcard = Wildcard::new("/*")?.unwrap();
-        assert!(matches!(wildcard, Wildcard::Universal(u) if u == "/*"));
+        assert!(matches!(wildcard, Wildcard::Universal(u) if u == ["/", ""]));
         Ok(())
     }
 
@@ -145,8 +163,25 @@ mod wildcard {
     }
 }
 
+/// The kind of wildcards used by the [`Rule`] pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WildcardKind {
+    /// No wildcards, e.g. `/fish`.
+    None,
+    /// Only the ending `$`, e.g. `/fish$`.
+    Ending,
+    /// Only the universal `*`, e.g. `/fish*.php`.
+    Universal,
+    /// Both the universal `*` and the ending `$`, e.g. `/*.php$`.
+    Both,
+}
+
 /// The `Rule` struct provides a convenient and efficient way to process
 /// and to match `robots.txt` provided patterns with relative paths.
+///
+/// Patterns are always anchored at the start of the path, i.e. `foo` is
+/// normalized into `/foo` and never matches `/barfoo`. Only the explicit
+/// leading `*` (as in `*foo`) matches anywhere in the path.
 #[derive(Debug, Clone)]
 pub struct Rule {
     pattern: String,
@@ -167,7 +202,6 @@ impl Rule {
         })
     }
 
-    #[cfg(feature = "serde")]
     /// Extracts a string slice containing the entire pattern.
     pub fn pattern(&self) -> &str {
         self.pattern.as_str()
@@ -182,18 +216,39 @@ impl Rule {
         }
     }
 
+    /// Returns the kind of wildcards used by the pattern.
+    ///
+    /// ```rust
+    /// use robotxt::{Rule, WildcardKind};
+    ///
+    /// let r = Rule::new("/*.php$", false).unwrap();
+    /// assert_eq!(r.wildcard_kind(), WildcardKind::Both);
+    /// ```
+    pub fn wildcard_kind(&self) -> WildcardKind {
+        match &self.wildcard {
+            None => WildcardKind::None,
+            Some(Wildcard::Ending(_)) => WildcardKind::Ending,
+            Some(Wildcard::Universal(_)) => WildcardKind::Universal,
+            Some(Wildcard::Both(_)) => WildcardKind::Both,
+        }
+    }
+
     /// Returns true if allowed.
     pub fn is_allowed(&self) -> bool {
         self.allow
     }
 
+    /// Returns the length of the pattern, used to determine precedence.
+    pub fn specificity(&self) -> usize {
+        self.pattern.len()
+    }
+
     /// Returns true if matches everything.
-    #[cfg(feature = "optimal")]
     pub(crate) fn is_universal(&self) -> bool {
         match &self.wildcard {
             None => self.pattern == "/",
             Some(Wildcard::Ending(_)) => false,
-            Some(Wildcard::Universal(p)) => p == "/*",
+            Some(Wildcard::Universal(s)) => s == &["/", ""],
             Some(Wildcard::Both(r)) => r.as_str() == "^/.*$",
         }
     }
@@ -215,7 +270,7 @@ impl PartialOrd<Self> for Rule {
 
 impl Ord for Rule {
     fn cmp(&self, other: &Self) -> Ordering {
-        let length = other.pattern.len().cmp(&self.pattern.len());
+        let length = other.specificity().cmp(&self.specificity());
         length.then_with(|| other.allow.cmp(&self.allow))
     }
 }
@@ -224,6 +279,32 @@ impl Ord for Rule {
 mod matching {
     use super::{Error, Rule};
 
+    #[test]
+    fn pattern() -> Result<(), Error> {
+        let r = Rule::new("fish*.php", false)?;
+        assert_eq!(r.pattern(), "/fish*.php");
+
+        Ok(())
+    }
+
+    #[test]
+    fn wildcard_kind() -> Result<(), Error> {
+        use super::WildcardKind;
+
+        let cases = [
+            ("/fish", WildcardKind::None),
+            ("/fish$", WildcardKind::Ending),
+            ("/fish*.php", WildcardKind::Universal),
+            ("/*.php$", WildcardKind::Both),
+        ];
+
+        for (pattern, kind) in cases {
+            assert_eq!(Rule::new(pattern, true)?.wildcard_kind(), kind, "{pattern}");
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn root_none() -> Result<(), Error> {
         let r = Rule::new("/", true)?;
@@ -281,6 +362,42 @@ mod matching {
         Ok(())
     }
 
+    #[test]
+    fn anchored() -> Result<(), Error> {
+        let r = Rule::new("foo", false)?;
+
+        // Matches:
+        assert!(r.is_match("/foo"));
+        assert!(r.is_match("/foobar"));
+
+        // Doesn't match:
+        assert!(!r.is_match("/barfoo"));
+        assert!(!r.is_match("/bar/foo"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn universal_start() -> Result<(), Error> {
+        for pattern in ["*foo", "/*foo"] {
+            let r = Rule::new(pattern, false)?;
+
+            // Matches:
+            assert!(r.is_match("/foo"));
+            assert!(r.is_match("/barfoo"));
+            assert!(r.is_match("/bar/foo.html"));
+
+            // Doesn't match:
+            assert!(!r.is_match("/bar"));
+        }
+
+        let r = Rule::new("*foo$", false)?;
+        assert!(r.is_match("/barfoo"));
+        assert!(!r.is_match("/barfoo.html"));
+
+        Ok(())
+    }
+
     #[test]
     fn folder() -> Result<(), Error> {
         let r = Rule::new("/fish/", true)?;
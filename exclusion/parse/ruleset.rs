@@ -0,0 +1,115 @@
+use std::hash::{Hash, Hasher};
+
+use crate::Robots;
+
+/// The [`Robots`] wrapper, that is compared and hashed by the applied rules
+/// only, ignoring the user-agent, crawl-delay and sitemaps.
+///
+/// Two [`RuleSet`]s are equal iff their normalized rule lists are equal i.e.
+/// they apply the same set of rules (in any order), or the same global rule.
+/// The parse options (e.g. [`crate::ParseOptions::robots_txt_always_allowed`])
+/// are not compared.
+/// Useful as a cache key to deduplicate the shared template `robots.txt`.
+///
+/// ```rust
+/// use std::collections::HashSet;
+/// use robotxt::{Robots, RuleSet};
+///
+/// let a = "User-Agent: * \n Disallow: /private/".as_bytes();
+/// let b = "User-Agent: * \n Disallow: /private/ \n Sitemap: https://example.com/1.xml".as_bytes();
+///
+/// let a = RuleSet::new(Robots::from_bytes(a, "foobot"));
+/// let b = RuleSet::new(Robots::from_bytes(b, "foobot"));
+/// let rulesets: HashSet<_> = [a, b].into_iter().collect();
+/// assert_eq!(rulesets.len(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RuleSet(Robots);
+
+impl RuleSet {
+    /// Creates a new instance with the given [`Robots`].
+    pub fn new(robots: Robots) -> Self {
+        Self(robots)
+    }
+
+    /// Returns the reference to the underlying [`Robots`].
+    pub fn as_robots(&self) -> &Robots {
+        &self.0
+    }
+
+    /// Returns the underlying [`Robots`].
+    pub fn into_inner(self) -> Robots {
+        self.0
+    }
+}
+
+impl From<Robots> for RuleSet {
+    fn from(robots: Robots) -> Self {
+        Self::new(robots)
+    }
+}
+
+impl PartialEq for RuleSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.rules_eq(&other.0)
+    }
+}
+
+impl Eq for RuleSet {}
+
+impl Hash for RuleSet {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.inner.normalized_rules().hash(state);
+    }
+}
+
+#[cfg(test)]
+mod equality {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    use super::*;
+
+    fn hash(ruleset: &RuleSet) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        ruleset.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn sitemaps_ignored() {
+        let a = b"User-Agent: * \n Disallow: /private/ \n Sitemap: https://example.com/1.xml";
+        let b = b"User-Agent: * \n Disallow: /private/ \n Sitemap: https://example.com/2.xml";
+
+        let a = RuleSet::new(Robots::from_bytes(a, "foobot"));
+        let b = RuleSet::new(Robots::from_bytes(b, "foobot"));
+        assert_eq!(a, b);
+        assert_eq!(hash(&a), hash(&b));
+    }
+
+    #[test]
+    fn rules_compared() {
+        let a = b"User-Agent: * \n Disallow: /private/";
+        let b = b"User-Agent: * \n Disallow: /public/";
+
+        let a = RuleSet::new(Robots::from_bytes(a, "foobot"));
+        let b = RuleSet::new(Robots::from_bytes(b, "foobot"));
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn global_rule() {
+        let a = RuleSet::new(Robots::from_bytes(
+            b"User-Agent: * \n Disallow: /",
+            "foobot",
+        ));
+        let b = RuleSet::new(Robots::from_always(false, "foobot"));
+        assert_eq!(a, b);
+        assert_eq!(hash(&a), hash(&b));
+
+        let a = RuleSet::new(Robots::from_bytes(b"", "foobot"));
+        let b = RuleSet::new(Robots::from_always(true, "foobot"));
+        assert_eq!(a, b);
+        assert_eq!(hash(&a), hash(&b));
+    }
+}
@@ -1,10 +1,23 @@
 use serde::de::{Error, MapAccess, Visitor};
 use serde::ser::SerializeStruct;
-use serde::{Deserializer, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::parse::inner::Rules;
 use crate::parse::rule::Rule;
 
+/// The compact serializable view of the effective rules only i.e. without
+/// the user-agent, crawl-delay and sitemaps.
+///
+/// See [`Robots::rules_only`] and [`Robots::from_rules_only`].
+///
+/// [`Robots::rules_only`]: crate::Robots::rules_only
+/// [`Robots::from_rules_only`]: crate::Robots::from_rules_only
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct RulesSnapshot {
+    pub(crate) rules: Rules,
+}
+
 impl serde::Serialize for Rules {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -110,7 +123,7 @@ impl<'de> serde::Deserialize<'de> for Rules {
 
 #[cfg(test)]
 mod cache {
-    use crate::Robots;
+    use crate::{Robots, RulesSnapshot};
 
     #[test]
     fn always() -> serde_json::Result<()> {
@@ -148,4 +161,41 @@ mod cache {
 
         Ok(())
     }
+
+    #[test]
+    fn older_format() -> serde_json::Result<()> {
+        let json = r#"{"user_agent":"foobot","allow":[],"disallow":["/a"],"crawl_delay":null,"sitemaps":[]}"#;
+        let r: Robots = serde_json::from_str(json)?;
+        assert_eq!(r.user_agent(), "foobot");
+        assert_eq!(r.user_agent_original(), "foobot");
+        assert!(!r.is_relative_allowed("/a"));
+        assert!(r.is_relative_allowed("/b"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn rules_only() -> serde_json::Result<()> {
+        let txt = r#"
+            User-Agent: foobot
+            Disallow: *
+            Allow: /example/
+            Disallow: /example/nope.txt
+            Crawl-Delay: 5
+            Sitemap: https://example.com/1.xml
+        "#;
+
+        let r0 = Robots::from_bytes(txt.as_bytes(), "foobot");
+        let json = serde_json::to_string(&r0.rules_only())?;
+        assert!(!json.contains("sitemap"));
+
+        let rules: RulesSnapshot = serde_json::from_str(&json)?;
+        let r1 = Robots::from_rules_only(rules, "foobot");
+        assert!(r1.is_relative_allowed("/example/yeah.txt"));
+        assert!(!r1.is_relative_allowed("/example/nope.txt"));
+        assert!(!r1.is_relative_allowed("/invalid/path.txt"));
+        assert_eq!(r1.crawl_delay(), None);
+
+        Ok(())
+    }
 }
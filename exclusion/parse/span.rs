@@ -0,0 +1,36 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The position of the directive value in the `robots.txt` file.
+///
+/// Offsets refer to the original input, including the UTF-16 one.
+/// Lines are counted after `\0` is replaced with the line break.
+///
+/// See [`ParseOptions::track_positions`].
+///
+/// [`ParseOptions::track_positions`]: crate::ParseOptions::track_positions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Span {
+    /// The line number (1-based) of the directive.
+    pub line: usize,
+    /// The byte offset of the first byte of the value.
+    pub start: usize,
+    /// The byte offset right after the last byte of the value.
+    pub end: usize,
+}
+
+impl Span {
+    /// Creates a new [`Span`] of the value, that is a subslice of the source.
+    pub(crate) fn from_subslice(source: &[u8], value: &[u8], line: usize) -> Self {
+        let start = value.as_ptr() as usize - source.as_ptr() as usize;
+        let end = start + value.len();
+        Self { line, start, end }
+    }
+
+    /// Maps both offsets, keeping the line number.
+    pub(crate) fn map(self, f: impl Fn(usize) -> usize) -> Self {
+        let (start, end) = (f(self.start), f(self.end));
+        Self { start, end, ..self }
+    }
+}
@@ -0,0 +1,53 @@
+use time::Time;
+
+/// Parses the `HHMM-HHMM` value of the non-standard `Visit-time` directive
+/// into the UTC window. Returns `None` if either time is malformed.
+///
+/// Windows that cross midnight (e.g. `2200-0600`) are kept as is.
+pub(crate) fn parse_visit_time(data: &str) -> Option<(Time, Time)> {
+    let (start, end) = data.trim().split_once('-')?;
+    Some((parse_time(start)?, parse_time(end)?))
+}
+
+/// Parses the `HHMM` time.
+fn parse_time(data: &str) -> Option<Time> {
+    let data = data.trim();
+    if data.len() != 4 || !data.bytes().all(|u| u.is_ascii_digit()) {
+        return None;
+    }
+
+    let (hour, minute) = data.split_at(2);
+    Time::from_hms(hour.parse().ok()?, minute.parse().ok()?, 0).ok()
+}
+
+#[cfg(test)]
+mod parsing {
+    use time::macros::time;
+
+    use super::*;
+
+    #[test]
+    fn valid() {
+        let exp = Some((time!(6:00), time!(8:45)));
+        assert_eq!(parse_visit_time("0600-0845"), exp);
+        assert_eq!(parse_visit_time(" 0600 - 0845 "), exp);
+
+        let exp = Some((time!(22:00), time!(6:00)));
+        assert_eq!(parse_visit_time("2200-0600"), exp);
+    }
+
+    #[test]
+    fn malformed() {
+        for data in [
+            "",
+            "0600",
+            "0600-",
+            "600-0845",
+            "0600-2400",
+            "0660-0845",
+            "06:00-08:45",
+        ] {
+            assert_eq!(parse_visit_time(data), None, "{data}");
+        }
+    }
+}
@@ -1,4 +1,6 @@
-pub use create::create_url;
+#[cfg(feature = "http")]
+pub use create::{create_request, create_request_with_agent};
+pub use create::{create_url, create_url_with_schemes};
 pub(crate) use normal::normalize_path;
 
 mod create;
@@ -7,3 +9,10 @@ mod normal;
 /// Google currently enforces a `robots.txt` file size limit of 500 kibibytes (KiB).
 /// See [How Google interprets Robots.txt](https://developers.google.com/search/docs/crawling-indexing/robots/robots_txt).
 pub const BYTE_LIMIT: usize = 512_000;
+
+/// The default maximal length of the matched path, longer paths are disallowed.
+/// Protects from adversarial paths causing slow wildcard matching.
+/// See [`ParseOptions::max_path_len`].
+///
+/// [`ParseOptions::max_path_len`]: crate::ParseOptions::max_path_len
+pub const PATH_LEN_LIMIT: usize = 65_536;
@@ -0,0 +1,113 @@
+use robotxt::{Robots, BYTE_LIMIT};
+use tokio::io::{AsyncBufRead, AsyncReadExt};
+use url::Url;
+
+use crate::{parse::AutoParser, Error};
+
+/// Fetches and parses the `robots.txt` file of the base address, and creates
+/// the [`AutoParser`] seeded with its sitemaps, i.e. the usual crawler startup.
+///
+/// The same fetcher is meant to be used with the [`AutoParser::try_async`].
+///
+/// ```rust
+/// #[derive(Debug, thiserror::Error)]
+/// enum CustomError {
+///     // ..
+///     #[error("sitemap error: {0}")]
+///     Sitemap(#[from] sitemapo::Error),
+///     //..
+/// }
+///
+/// #[tokio::main(flavor = "current_thread")]
+/// async fn main() -> Result<(), CustomError> {
+///     type AsyncReader = tokio::io::BufReader<std::io::Cursor<Vec<u8>>>;
+///     async fn fetch(_: url::Url) -> Result<AsyncReader, CustomError> {
+///         // ..
+///         let cursor = std::io::Cursor::new(Vec::new());
+///         Ok(tokio::io::BufReader::new(cursor))
+///     }
+///
+///     let base = url::Url::parse("https://example.com/").unwrap();
+///     let (robots, mut parser) = sitemapo::bootstrap(&base, "foobot", fetch).await?;
+///     while let Some(_record) = parser.try_async(fetch).await? {
+///         // ..
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [`AutoParser::try_async`]: AutoParser::try_async
+pub async fn bootstrap<R, E, A, F>(
+    base: &Url,
+    user_agent: &str,
+    fetcher: A,
+) -> Result<(Robots, AutoParser<R>), E>
+where
+    R: AsyncBufRead + Unpin + Send,
+    E: std::error::Error + From<Error>,
+    F: std::future::Future<Output = Result<R, E>>,
+    A: Fn(Url) -> F,
+{
+    let addr = robotxt::create_url(base).map_err(Error::from)?;
+    let reader = (fetcher)(addr).await?;
+
+    let mut buf = Vec::new();
+    let mut reader = reader.take(BYTE_LIMIT as u64);
+    reader.read_to_end(&mut buf).await.map_err(Error::from)?;
+
+    let robots = Robots::from_bytes(&buf, user_agent);
+    let parser = AutoParser::new(robots.sitemaps().iter().cloned());
+    Ok((robots, parser))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Debug, thiserror::Error)]
+    enum CustomError {
+        #[error("sitemap error: {0}")]
+        Sitemap(#[from] Error),
+    }
+
+    const ROBOTS: &str = r#"
+        User-Agent: foobot
+        Disallow: /private/
+        Sitemap: https://example.com/entry.xml
+    "#;
+
+    const ENTRY: &str = r#"
+    <urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+        <url><loc>https://example.com/file1.html</loc></url>
+        <url><loc>https://example.com/file2.html</loc></url>
+    </urlset>"#;
+
+    type AsyncReader = tokio::io::BufReader<std::io::Cursor<Vec<u8>>>;
+    async fn async_fetcher(url: Url) -> Result<AsyncReader, CustomError> {
+        let txt = match url.path() {
+            "/robots.txt" => ROBOTS,
+            "/entry.xml" => ENTRY,
+            _ => unreachable!(),
+        };
+
+        let cursor = std::io::Cursor::new(txt.as_bytes().to_vec());
+        Ok(tokio::io::BufReader::new(cursor))
+    }
+
+    #[tokio::test]
+    async fn asynk() -> Result<(), CustomError> {
+        let base = Url::parse("https://example.com/page.html").unwrap();
+        let (robots, mut parser) = bootstrap(&base, "foobot", async_fetcher).await?;
+        assert!(!robots.is_relative_allowed("/private/"));
+        assert_eq!(parser.len(), 1);
+
+        let mut records = Vec::new();
+        while let Some(record) = parser.try_async(async_fetcher).await? {
+            records.push(record);
+        }
+
+        assert_eq!(records.len(), 2);
+        Ok(())
+    }
+}
@@ -32,7 +32,7 @@ pub struct AutoBuilder<W> {
 impl<W> AutoBuilder<W> {
     /// TODO: Desc.
     pub fn new() -> Self {
-        todo!()
+        Self::default()
     }
 }
 
@@ -41,7 +41,7 @@ where
     W: std::io::Write,
 {
     /// TODO: Desc.
-    pub fn try_sync<E, A>(&mut self, fetcher: A) -> Result<(), E>
+    pub fn try_sync<E, A>(&mut self, _fetcher: A) -> Result<(), E>
     where
         E: std::error::Error + From<Error>,
         A: Fn(Url) -> Result<Vec<Entry>, E>,
@@ -68,19 +68,23 @@ where
 
 impl<W> std::fmt::Debug for AutoBuilder<W> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        // TODO: Debug.
-        f.debug_struct("AutoBuilder").finish()
+        f.debug_struct("AutoBuilder")
+            .field("index", &self.index)
+            .field("entry", &self.entry)
+            .field("queue", &self.queue)
+            .finish()
     }
 }
 
-// impl<W> Default for AutoBuilder<W> {
-//     fn default() -> Self {
-//         Self {
-//             entry: None,
-//             index: None,
-//         }
-//     }
-// }
+impl<W> Default for AutoBuilder<W> {
+    fn default() -> Self {
+        Self {
+            index: None,
+            entry: Vec::new(),
+            queue: Vec::new(),
+        }
+    }
+}
 
 #[cfg(test)]
 mod test {
@@ -1,9 +1,8 @@
 use std::io::Write;
 
-use quick_xml::{events, Writer};
-use time::format_description::well_known::Iso8601;
+use quick_xml::events;
 
-use crate::build::{Builder, InnerBuilder, CONFIG};
+use crate::build::{Builder, InnerBuilder};
 use crate::record::*;
 use crate::{Error, Result};
 
@@ -41,8 +40,12 @@ use crate::{Error, Result};
 /// ```
 pub struct EntryBuilder<W> {
     inner: InnerBuilder<W, Entry>,
+    validator: Option<Box<Validator>>,
 }
 
+/// Custom validation of the record before it is written.
+type Validator = dyn Fn(&Entry) -> std::result::Result<(), String> + Send + Sync;
+
 impl<W> EntryBuilder<W> {
     /// Creates a new instance with the given writer.
     pub(crate) fn from_writer(writer: W) -> Self {
@@ -52,7 +55,94 @@ impl<W> EntryBuilder<W> {
 
     /// Creates a new instance with the given inner parser.
     pub(crate) fn from_inner(inner: InnerBuilder<W, Entry>) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            validator: None,
+        }
+    }
+
+    /// Enables or disables the leading byte order mark. Enabled by default.
+    /// Has no effect once the first record is written.
+    ///
+    /// ```rust
+    /// use sitemapo::build::{Builder, EntryBuilder};
+    ///
+    /// fn main() -> sitemapo::Result<()> {
+    ///     let builder = EntryBuilder::new(Vec::new())?.with_bom(false);
+    ///     let buf = builder.close()?;
+    ///     assert!(buf.starts_with(b"<?xml"));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_bom(mut self, bom: bool) -> Self {
+        self.inner = self.inner.with_bom(bom);
+        self
+    }
+
+    /// Enables or disables the leading `<?xml?>` declaration. Enabled by default.
+    /// Has no effect once the first record is written.
+    pub fn with_declaration(mut self, declaration: bool) -> Self {
+        self.inner = self.inner.with_declaration(declaration);
+        self
+    }
+
+    /// Enables or disables the `xmlns:xsi` and `xsi:schemaLocation` attributes
+    /// of the root tag, required by some validators. Disabled by default.
+    /// Has no effect once the first record is written.
+    pub fn with_schema_location(mut self, schema_location: bool) -> Self {
+        self.inner = self.inner.with_schema_location(schema_location);
+        self
+    }
+
+    /// Sets the custom validator, that runs before every record is written.
+    /// Rejected records result in the [`Error::Validation`] and are not written.
+    ///
+    /// ```rust
+    /// use sitemapo::build::{Builder, EntryBuilder};
+    /// use sitemapo::record::Entry;
+    ///
+    /// fn main() -> sitemapo::Result<()> {
+    ///     let mut builder = EntryBuilder::new(Vec::new())?;
+    ///     builder.set_validator(|record| match record.location.path().starts_with("/blog/") {
+    ///         true => Ok(()),
+    ///         false => Err(format!("unexpected path: {}", record.location.path())),
+    ///     });
+    ///
+    ///     let url = "https://example.com/".try_into().unwrap();
+    ///     assert!(builder.write(&Entry::new(url)).is_err());
+    ///     let _buf = builder.close()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_validator<F>(&mut self, validator: F)
+    where
+        F: Fn(&Entry) -> std::result::Result<(), String> + Send + Sync + 'static,
+    {
+        self.validator = Some(Box::new(validator));
+    }
+
+    /// Enables or disables `lastmod` as a bare date (e.g. `2022-06-04`) for
+    /// timestamps at midnight UTC. Disabled by default.
+    ///
+    /// ```rust
+    /// use time::macros::datetime;
+    /// use sitemapo::build::{Builder, EntryBuilder};
+    /// use sitemapo::record::Entry;
+    ///
+    /// fn main() -> sitemapo::Result<()> {
+    ///     let url = "https://example.com/".try_into().unwrap();
+    ///     let rec = Entry::new(url).with_modified(datetime!(2022-06-04 0:00 UTC));
+    ///
+    ///     let mut builder = EntryBuilder::new(Vec::new())?.with_date_only_lastmod(true);
+    ///     builder.write(&rec)?;
+    ///     let buf = builder.close()?;
+    ///     assert!(String::from_utf8_lossy(&buf).contains("<lastmod>2022-06-04</lastmod>"));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_date_only_lastmod(mut self, date_only: bool) -> Self {
+        self.inner = self.inner.with_date_only(date_only);
+        self
     }
 
     /// Returns a reference to the underlying writer.
@@ -71,7 +161,7 @@ impl<W> EntryBuilder<W> {
     }
 
     pub(crate) fn create_entry_open(&mut self) -> Result<Vec<u8>> {
-        self.inner.create_open_tag(URL_SET)
+        self.inner.create_open_tag(URL_SET, URL_SET_SCHEMA)
     }
 
     pub(crate) fn create_entry_record(&mut self, record: &Entry) -> Result<Vec<u8>> {
@@ -79,43 +169,92 @@ impl<W> EntryBuilder<W> {
             return Err(Error::EntryLimit { over: 1 });
         }
 
-        let format = &Iso8601::<{ CONFIG }>;
+        if let Some(validator) = &self.validator {
+            validator(record).map_err(Error::Validation)?;
+        }
+
         let location = record.location.to_string();
-        let modified = record.modified.map(|u| u.format(format).unwrap());
+        let modified = record.modified.map(|u| self.inner.format_modified(u));
         let priority = record.priority.map(|u| u.to_string());
         let frequency = record.frequency.map(|u| u.to_string());
 
-        let mut temp = Writer::new(Vec::new());
-        let element = temp.create_element(URL);
-        element.write_inner_content(|writer| -> quick_xml::Result<()> {
-            let tag = writer.create_element(LOCATION);
-            tag.write_text_content(events::BytesText::new(&location))?;
-
-            if let Some(modified) = modified {
-                let tag = writer.create_element(LAST_MODIFIED);
-                tag.write_text_content(events::BytesText::new(&modified))?;
-            }
-
-            if let Some(priority) = priority {
-                let tag = writer.create_element(PRIORITY);
-                tag.write_text_content(events::BytesText::new(&priority))?;
-            }
-
-            if let Some(frequency) = frequency {
-                let tag = writer.create_element(CHANGE_FREQUENCY);
-                tag.write_text_content(events::BytesText::new(&frequency))?;
-            }
+        self.inner.create_record(URL_SET, |temp| {
+            let element = temp.create_element(URL);
+            #[cfg(feature = "extension")]
+            let element = element.with_attributes(
+                (record.attributes.iter()).map(|(key, value)| (key.as_str(), value.as_str())),
+            );
+
+            element.write_inner_content(|writer| -> quick_xml::Result<()> {
+                let tag = writer.create_element(LOCATION);
+                tag.write_text_content(events::BytesText::new(&location))?;
+
+                if let Some(modified) = modified {
+                    let tag = writer.create_element(LAST_MODIFIED);
+                    tag.write_text_content(events::BytesText::new(&modified))?;
+                }
+
+                if let Some(priority) = priority {
+                    let tag = writer.create_element(PRIORITY);
+                    tag.write_text_content(events::BytesText::new(&priority))?;
+                }
+
+                if let Some(frequency) = frequency {
+                    let tag = writer.create_element(CHANGE_FREQUENCY);
+                    tag.write_text_content(events::BytesText::new(&frequency))?;
+                }
+
+                #[cfg(feature = "extension")]
+                for image in record.images.iter() {
+                    // Extension namespaces are declared on their own elements,
+                    // so records without extensions are written as before.
+                    let tag = writer.create_element(IMAGE).with_attribute(IMAGE_XMLNS);
+                    tag.write_inner_content(|writer| -> quick_xml::Result<()> {
+                        let tag = writer.create_element(IMAGE_LOCATION);
+                        tag.write_text_content(events::BytesText::new(image.as_str()))?;
+                        Ok(())
+                    })?;
+                }
+
+                #[cfg(feature = "extension")]
+                for video in record.videos.iter() {
+                    let tag = writer.create_element(VIDEO).with_attribute(VIDEO_XMLNS);
+                    tag.write_inner_content(|writer| -> quick_xml::Result<()> {
+                        let thumbnail = video.thumbnail_location.as_str();
+                        let tag = writer.create_element(VIDEO_THUMBNAIL_LOCATION);
+                        tag.write_text_content(events::BytesText::new(thumbnail))?;
+
+                        let tag = writer.create_element(VIDEO_TITLE);
+                        tag.write_text_content(events::BytesText::new(&video.title))?;
+
+                        let tag = writer.create_element(VIDEO_DESCRIPTION);
+                        tag.write_text_content(events::BytesText::new(&video.description))?;
+
+                        if let Some(content) = &video.content_location {
+                            let tag = writer.create_element(VIDEO_CONTENT_LOCATION);
+                            tag.write_text_content(events::BytesText::new(content.as_str()))?;
+                        }
+
+                        Ok(())
+                    })?;
+                }
+
+                #[cfg(feature = "extension")]
+                for alternate in record.alternates.iter() {
+                    let tag = writer
+                        .create_element(XHTML_LINK)
+                        .with_attribute(XHTML_XMLNS);
+                    let tag = tag.with_attribute(("rel", "alternate"));
+                    let tag = tag.with_attribute(("hreflang", alternate.hreflang.as_str()));
+                    let tag = tag.with_attribute(("href", alternate.href.as_str()));
+                    tag.write_empty()?;
+                }
+
+                Ok(())
+            })?;
 
             Ok(())
-        })?;
-
-        let buf = temp.into_inner();
-        if buf.len() > BYTE_LIMIT {
-            let over_limit = buf.len() - BYTE_LIMIT;
-            return Err(Error::ByteLimit { over: over_limit });
-        }
-
-        Ok(buf)
+        })
     }
 
     pub(crate) fn create_entry_close(&mut self) -> Result<Vec<u8>> {
@@ -127,21 +266,47 @@ impl<W> std::fmt::Debug for EntryBuilder<W> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("EntryBuilder")
             .field("inner", &self.inner)
+            .field("validator", &self.validator.is_some())
             .finish()
     }
 }
 
+impl<W: Write> EntryBuilder<W> {
+    /// Creates a new instance with the given writer, that indents nested elements.
+    ///
+    /// Produces human-readable output at the cost of the larger file size.
+    ///
+    /// ```rust
+    /// use sitemapo::build::{Builder, EntryBuilder};
+    /// use sitemapo::record::Entry;
+    ///
+    /// fn main() -> sitemapo::Result<()> {
+    ///     let buf = Vec::new();
+    ///     let url = "https://example.com/".try_into().unwrap();
+    ///     let rec = Entry::new(url);
+    ///
+    ///     let mut builder = EntryBuilder::new_pretty(buf)?;
+    ///     builder.write(&rec)?;
+    ///     let _buf = builder.close()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new_pretty(writer: W) -> Result<Self> {
+        let inner = InnerBuilder::from_writer(writer).with_pretty(true);
+        Ok(Self::from_inner(inner))
+    }
+}
+
 impl<W: Write> Builder<W, Entry> for EntryBuilder<W> {
     type Error = Error;
 
     fn new(writer: W) -> Result<Self> {
-        let mut this = Self::from_writer(writer);
-        let temp = this.create_entry_open()?;
-        this.inner.writer.write_all(&temp)?;
-        Ok(this)
+        Ok(Self::from_writer(writer))
     }
 
     fn write(&mut self, record: &Entry) -> Result<()> {
+        let temp = self.create_entry_open()?;
+        self.inner.writer.write_all(&temp)?;
         let temp = self.create_entry_record(record)?;
         self.inner.writer.write_all(&temp)?;
         self.inner.records += 1;
@@ -149,8 +314,11 @@ impl<W: Write> Builder<W, Entry> for EntryBuilder<W> {
     }
 
     fn close(mut self) -> Result<W> {
+        let temp = self.create_entry_open()?;
+        self.inner.writer.write_all(&temp)?;
         let temp = self.create_entry_close()?;
         self.inner.writer.write_all(&temp)?;
+        self.inner.writer.flush()?;
         Ok(self.into_inner())
     }
 }
@@ -161,22 +329,30 @@ mod tokio {
     use async_trait::async_trait;
     use tokio::io::{AsyncWrite, AsyncWriteExt};
 
-    use crate::build::{AsyncBuilder, EntryBuilder};
+    use crate::build::{AsyncBuilder, EntryBuilder, InnerBuilder};
     use crate::record::Entry;
     use crate::{Error, Result};
 
+    impl<W: AsyncWrite + Unpin + Send> EntryBuilder<W> {
+        /// Creates a new instance with the given writer, that indents nested elements.
+        /// See [`EntryBuilder::new_pretty`].
+        pub async fn new_pretty_async(writer: W) -> Result<Self> {
+            let inner = InnerBuilder::from_writer(writer).with_pretty(true);
+            Ok(Self::from_inner(inner))
+        }
+    }
+
     #[async_trait]
     impl<W: AsyncWrite + Unpin + Send> AsyncBuilder<W, Entry> for EntryBuilder<W> {
         type Error = Error;
 
         async fn new(writer: W) -> Result<Self> {
-            let mut this = Self::from_writer(writer);
-            let temp = this.create_entry_open()?;
-            this.inner.writer.write_all(&temp).await?;
-            Ok(this)
+            Ok(Self::from_writer(writer))
         }
 
         async fn write(&mut self, record: &Entry) -> Result<()> {
+            let temp = self.create_entry_open()?;
+            self.inner.writer.write_all(&temp).await?;
             let temp = self.create_entry_record(record)?;
             self.inner.writer.write_all(&temp).await?;
             self.inner.records += 1;
@@ -184,8 +360,11 @@ mod tokio {
         }
 
         async fn close(mut self) -> Result<W> {
+            let temp = self.create_entry_open()?;
+            self.inner.writer.write_all(&temp).await?;
             let temp = self.create_entry_close()?;
             self.inner.writer.write_all(&temp).await?;
+            self.inner.writer.flush().await?;
             Ok(self.into_inner())
         }
     }
@@ -226,6 +405,276 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn synk_validator() -> Result<()> {
+        use crate::parse::{EntryParser, Parser};
+        use crate::Error;
+
+        let mut builder = EntryBuilder::new(Vec::new())?;
+        builder.set_validator(
+            |record| match record.location.path().starts_with("/blog/") {
+                true => Ok(()),
+                false => Err("off-prefix url".to_string()),
+            },
+        );
+
+        let url = Url::parse("https://example.com/blog/post.html").unwrap();
+        builder.write(&Entry::new(url))?;
+
+        let url = Url::parse("https://example.com/about.html").unwrap();
+        let result = builder.write(&Entry::new(url));
+        assert!(matches!(result, Err(Error::Validation(msg)) if msg == "off-prefix url"));
+
+        let buf = builder.close()?;
+        let mut parser = EntryParser::new(buf.as_slice())?;
+        let record = parser.read()?.unwrap();
+        assert_eq!(record.location.path(), "/blog/post.html");
+        assert!(parser.read()?.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn synk_byte_limit() -> Result<()> {
+        use crate::record::BYTE_LIMIT;
+        use crate::Error;
+
+        let path = "a".repeat(40_000);
+        let url = Url::parse(&format!("https://example.com/{path}")).unwrap();
+        let entry = Entry::new(url);
+
+        let mut builder = EntryBuilder::new(Vec::new())?;
+        builder.write(&entry)?;
+        let opened = builder.get_ref().len();
+        builder.write(&entry)?;
+        let record = builder.get_ref().len() - opened;
+
+        let result = loop {
+            if let Err(err) = builder.write(&entry) {
+                break err;
+            }
+        };
+
+        // Fails on the first record that (with the closing tag) crosses the limit.
+        assert!(matches!(result, Error::ByteLimit { .. }));
+        let buf = builder.close()?;
+        assert!(buf.len() <= BYTE_LIMIT);
+        assert!(buf.len() + record > BYTE_LIMIT);
+
+        Ok(())
+    }
+
+    #[test]
+    fn synk_date_only() -> Result<()> {
+        use time::macros::datetime;
+
+        let url = Url::parse("https://example.com/").unwrap();
+        let midnight = Entry::new(url.clone()).with_modified(datetime!(2022-06-04 0:00 UTC));
+        let noon = Entry::new(url.clone()).with_modified(datetime!(2022-06-04 12:00 UTC));
+        let local = Entry::new(url).with_modified(datetime!(2022-06-04 0:00 +2));
+
+        let mut builder = EntryBuilder::new(Vec::new())?.with_date_only_lastmod(true);
+        builder.write(&midnight)?;
+        builder.write(&noon)?;
+        builder.write(&local)?;
+        let buf = String::from_utf8(builder.close()?).unwrap();
+        assert!(buf.contains("<lastmod>2022-06-04</lastmod>"));
+        assert!(buf.contains("<lastmod>2022-06-04T12:00:00.00Z</lastmod>"));
+        assert!(buf.contains("<lastmod>2022-06-04T00:00:00.00+02:00</lastmod>"));
+
+        let mut builder = EntryBuilder::new(Vec::new())?;
+        builder.write(&midnight)?;
+        let buf = String::from_utf8(builder.close()?).unwrap();
+        assert!(buf.contains("<lastmod>2022-06-04T00:00:00.00Z</lastmod>"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn synk_plain_output() -> Result<()> {
+        let url = Url::parse("https://example.com/").unwrap();
+        let mut builder = EntryBuilder::new(Vec::new())?;
+        builder.write(&Entry::new(url))?;
+        let buf = builder.close()?;
+
+        // Records without extensions don't declare their namespaces.
+        let exp = "\u{feff}<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+            <urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\
+            <url><loc>https://example.com/</loc></url></urlset>";
+        assert_eq!(String::from_utf8(buf).unwrap(), exp);
+
+        Ok(())
+    }
+
+    #[test]
+    fn synk_schema_location() -> Result<()> {
+        use crate::parse::{EntryParser, Parser};
+
+        let builder = EntryBuilder::new(Vec::new())?;
+        let buf = String::from_utf8(builder.close()?).unwrap();
+        assert!(!buf.contains("xsi:schemaLocation"));
+
+        let url = Url::parse("https://example.com/").unwrap();
+        let mut builder = EntryBuilder::new(Vec::new())?.with_schema_location(true);
+        builder.write(&Entry::new(url.clone()))?;
+        let buf = builder.close()?;
+
+        let text = String::from_utf8_lossy(&buf);
+        assert!(text.contains(r#"xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance""#));
+        assert!(text.contains(r#"xsi:schemaLocation="http://www.sitemaps.org/schemas/sitemap/0.9 http://www.sitemaps.org/schemas/sitemap/0.9/sitemap.xsd""#));
+
+        let mut parser = EntryParser::new(buf.as_slice())?.with_strict(true);
+        assert_eq!(parser.read()?.unwrap().location, url);
+
+        Ok(())
+    }
+
+    #[test]
+    fn synk_prolog() -> Result<()> {
+        use crate::parse::{EntryParser, Parser};
+
+        for (bom, declaration) in [(true, true), (true, false), (false, true), (false, false)] {
+            let builder = EntryBuilder::new(Vec::new())?;
+            let mut builder = builder.with_bom(bom).with_declaration(declaration);
+
+            let url = Url::parse("https://example.com/").unwrap();
+            builder.write(&Entry::new(url.clone()))?;
+            let buf = builder.close()?;
+
+            let buf_bom = buf.strip_prefix(b"\xEF\xBB\xBF");
+            assert_eq!(buf_bom.is_some(), bom);
+            let buf_decl = buf_bom.unwrap_or(&buf).starts_with(b"<?xml");
+            assert_eq!(buf_decl, declaration);
+
+            let mut parser = EntryParser::new(buf.as_slice())?;
+            let record = parser.read()?.unwrap();
+            assert_eq!(record.location, url);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn synk_pretty() -> Result<()> {
+        use crate::parse::{EntryParser, Parser};
+
+        let buf = Vec::new();
+        let mut builder = EntryBuilder::new_pretty(buf)?;
+
+        let url = Url::parse("https://example.com/").unwrap();
+        builder.write(&Entry::new(url.clone()))?;
+        let buf = builder.close()?;
+
+        let txt = String::from_utf8(buf.clone()).unwrap();
+        let exp = "\n    <url>\n        <loc>https://example.com/</loc>\n    </url>\n</urlset>";
+        assert!(txt.ends_with(exp), "{txt}");
+
+        let mut parser = EntryParser::new(buf.as_slice())?;
+        let record = parser.read()?.unwrap();
+        assert_eq!(record.location, url);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "extension")]
+    #[test]
+    fn synk_images() -> Result<()> {
+        use crate::parse::{EntryParser, Parser};
+
+        let buf = Vec::new();
+        let mut builder = EntryBuilder::new(buf)?;
+
+        let url = Url::parse("https://example.com/").unwrap();
+        let img1 = Url::parse("https://example.com/1.png").unwrap();
+        let img2 = Url::parse("https://example.com/2.png").unwrap();
+        let rec = Entry::new(url)
+            .with_image(img1.clone())
+            .with_image(img2.clone());
+        builder.write(&rec)?;
+        let buf = builder.close()?;
+
+        let mut parser = EntryParser::new(buf.as_slice())?;
+        let record = parser.read()?.unwrap();
+        assert_eq!(record.images, vec![img1, img2]);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "extension")]
+    #[test]
+    fn synk_videos() -> Result<()> {
+        use crate::parse::{EntryParser, Parser};
+        use crate::record::VideoEntry;
+
+        let buf = Vec::new();
+        let mut builder = EntryBuilder::new(buf)?;
+
+        let url = Url::parse("https://example.com/").unwrap();
+        let thumbnail = Url::parse("https://example.com/1.png").unwrap();
+        let content = Url::parse("https://example.com/1.mp4").unwrap();
+        let video = VideoEntry::new(thumbnail.clone(), "Title & Co", "Description");
+        let rec = Entry::new(url).with_video(video.with_content(content.clone()));
+        builder.write(&rec)?;
+        let buf = builder.close()?;
+
+        let mut parser = EntryParser::new(buf.as_slice())?;
+        let record = parser.read()?.unwrap();
+        assert_eq!(record.videos.len(), 1);
+        assert_eq!(record.videos[0].thumbnail_location, thumbnail);
+        assert_eq!(record.videos[0].title, "Title & Co");
+        assert_eq!(record.videos[0].content_location, Some(content));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "extension")]
+    #[test]
+    fn synk_attributes() -> Result<()> {
+        use crate::parse::{EntryParser, Parser};
+
+        let buf = Vec::new();
+        let mut builder = EntryBuilder::new(buf)?;
+
+        let url = Url::parse("https://example.com/").unwrap();
+        let rec = Entry::new(url).with_attribute("xml:lang", "en & co");
+        builder.write(&rec)?;
+        let buf = builder.close()?;
+
+        let txt = String::from_utf8(buf.clone()).unwrap();
+        assert!(txt.contains(r#"<url xml:lang="en &amp; co">"#), "{txt}");
+
+        let mut parser = EntryParser::new(buf.as_slice())?;
+        let record = parser.read()?.unwrap();
+        let exp = [("xml:lang".to_string(), "en & co".to_string())];
+        assert_eq!(record.attributes, exp);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "extension")]
+    #[test]
+    fn synk_alternates() -> Result<()> {
+        use crate::parse::{EntryParser, Parser};
+        use crate::record::Alternate;
+
+        let buf = Vec::new();
+        let mut builder = EntryBuilder::new(buf)?;
+
+        let url = Url::parse("https://example.com/").unwrap();
+        let href = Url::parse("https://example.com/de/").unwrap();
+        let rec = Entry::new(url).with_alternate(Alternate::new("de", href.clone()));
+        builder.write(&rec)?;
+        let buf = builder.close()?;
+
+        let mut parser = EntryParser::new(buf.as_slice())?;
+        let record = parser.read()?.unwrap();
+        assert_eq!(record.alternates.len(), 1);
+        assert_eq!(record.alternates[0].hreflang, "de");
+        assert_eq!(record.alternates[0].href, href);
+
+        Ok(())
+    }
 }
 
 #[cfg(feature = "tokio")]
@@ -261,7 +710,7 @@ mod tokio_test {
         builder.write(&rec).await?;
         let mut buf = builder.close().await?;
 
-        let _ = buf.flush().await?;
+        buf.flush().await?;
 
         Ok(())
     }
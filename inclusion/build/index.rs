@@ -1,9 +1,10 @@
 use std::io::Write;
 
-use quick_xml::{events, Writer};
-use time::format_description::well_known::Iso8601;
+use quick_xml::events;
+use time::OffsetDateTime;
+use url::Url;
 
-use crate::build::{Builder, InnerBuilder, CONFIG};
+use crate::build::{Builder, InnerBuilder};
 use crate::record::*;
 use crate::{Error, Result};
 
@@ -55,6 +56,63 @@ impl<W> IndexBuilder<W> {
         Self { inner }
     }
 
+    /// Enables or disables the leading byte order mark. Enabled by default.
+    /// Has no effect once the first record is written.
+    ///
+    /// ```rust
+    /// use sitemapo::build::{Builder, IndexBuilder};
+    ///
+    /// fn main() -> sitemapo::Result<()> {
+    ///     let builder = IndexBuilder::new(Vec::new())?.with_bom(false);
+    ///     let buf = builder.close()?;
+    ///     assert!(buf.starts_with(b"<?xml"));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_bom(mut self, bom: bool) -> Self {
+        self.inner = self.inner.with_bom(bom);
+        self
+    }
+
+    /// Enables or disables the leading `<?xml?>` declaration. Enabled by default.
+    /// Has no effect once the first record is written.
+    pub fn with_declaration(mut self, declaration: bool) -> Self {
+        self.inner = self.inner.with_declaration(declaration);
+        self
+    }
+
+    /// Enables or disables the `xmlns:xsi` and `xsi:schemaLocation` attributes
+    /// of the root tag, required by some validators. Disabled by default.
+    /// Has no effect once the first record is written.
+    pub fn with_schema_location(mut self, schema_location: bool) -> Self {
+        self.inner = self.inner.with_schema_location(schema_location);
+        self
+    }
+
+    /// Enables or disables `lastmod` as a bare date (e.g. `2022-06-04`) for
+    /// timestamps at midnight UTC. Disabled by default.
+    ///
+    /// ```rust
+    /// use time::macros::datetime;
+    /// use sitemapo::build::{Builder, IndexBuilder};
+    /// use sitemapo::record::Index;
+    ///
+    /// fn main() -> sitemapo::Result<()> {
+    ///     let url = "https://example.com/".try_into().unwrap();
+    ///     let rec = Index::new(url).with_modified(datetime!(2022-06-04 0:00 UTC));
+    ///
+    ///     let mut builder = IndexBuilder::new(Vec::new())?.with_date_only_lastmod(true);
+    ///     builder.write(&rec)?;
+    ///     let buf = builder.close()?;
+    ///     assert!(String::from_utf8_lossy(&buf).contains("<lastmod>2022-06-04</lastmod>"));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_date_only_lastmod(mut self, date_only: bool) -> Self {
+        self.inner = self.inner.with_date_only(date_only);
+        self
+    }
+
     /// Returns a reference to the underlying writer.
     pub fn get_ref(&self) -> &W {
         self.inner.get_ref()
@@ -71,7 +129,8 @@ impl<W> IndexBuilder<W> {
     }
 
     pub(crate) fn create_index_open(&mut self) -> Result<Vec<u8>> {
-        self.inner.create_open_tag(SITEMAP_INDEX)
+        self.inner
+            .create_open_tag(SITEMAP_INDEX, SITEMAP_INDEX_SCHEMA)
     }
 
     pub(crate) fn create_index_record(&mut self, record: &Index) -> Result<Vec<u8>> {
@@ -79,31 +138,25 @@ impl<W> IndexBuilder<W> {
             return Err(Error::EntryLimit { over: 1 });
         }
 
-        let format = &Iso8601::<{ CONFIG }>;
         let location = record.location.to_string();
-        let modified = record.modified.map(|u| u.format(format).unwrap());
+        let modified = record.modified.map(|u| self.inner.format_modified(u));
 
-        let mut temp = Writer::new(Vec::new());
-        let element = temp.create_element(SITEMAP);
-        element.write_inner_content(|writer| -> quick_xml::Result<()> {
-            let tag = writer.create_element(LOCATION);
-            tag.write_text_content(events::BytesText::new(&location))?;
+        self.inner.create_record(SITEMAP_INDEX, |temp| {
+            let element = temp.create_element(SITEMAP);
+            element.write_inner_content(|writer| -> quick_xml::Result<()> {
+                let tag = writer.create_element(LOCATION);
+                tag.write_text_content(events::BytesText::new(&location))?;
 
-            if let Some(modified) = modified {
-                let tag = writer.create_element(LAST_MODIFIED);
-                tag.write_text_content(events::BytesText::new(&modified))?;
-            }
+                if let Some(modified) = modified {
+                    let tag = writer.create_element(LAST_MODIFIED);
+                    tag.write_text_content(events::BytesText::new(&modified))?;
+                }
 
-            Ok(())
-        })?;
+                Ok(())
+            })?;
 
-        let buf = temp.into_inner();
-        if buf.len() > BYTE_LIMIT {
-            let over_limit = buf.len() - BYTE_LIMIT;
-            return Err(Error::ByteLimit { over: over_limit });
-        }
-
-        Ok(buf)
+            Ok(())
+        })
     }
 
     pub(crate) fn create_index_close(&mut self) -> Result<Vec<u8>> {
@@ -119,17 +172,71 @@ impl<W> std::fmt::Debug for IndexBuilder<W> {
     }
 }
 
+impl<W: Write> IndexBuilder<W> {
+    /// Creates a new instance with the given writer, that indents nested elements.
+    ///
+    /// Produces human-readable output at the cost of the larger file size.
+    ///
+    /// ```rust
+    /// use sitemapo::build::{Builder, IndexBuilder};
+    /// use sitemapo::record::Index;
+    ///
+    /// fn main() -> sitemapo::Result<()> {
+    ///     let buf = Vec::new();
+    ///     let url = "https://example.com/".try_into().unwrap();
+    ///     let rec = Index::new(url);
+    ///
+    ///     let mut builder = IndexBuilder::new_pretty(buf)?;
+    ///     builder.write(&rec)?;
+    ///     let _buf = builder.close()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new_pretty(writer: W) -> Result<Self> {
+        let inner = InnerBuilder::from_writer(writer).with_pretty(true);
+        Ok(Self::from_inner(inner))
+    }
+
+    /// Writes a record for every location with the optional modify timestamp.
+    /// Stops at the first failure e.g. when the limit is reached.
+    ///
+    /// ```rust
+    /// use sitemapo::build::{Builder, IndexBuilder};
+    ///
+    /// fn main() -> sitemapo::Result<()> {
+    ///     let shards = (1..=3).map(|i| {
+    ///         let url = format!("https://example.com/sitemap_{i}.xml");
+    ///         (url.as_str().try_into().unwrap(), None)
+    ///     });
+    ///
+    ///     let mut builder = IndexBuilder::new(Vec::new())?;
+    ///     builder.extend_from_locations(shards)?;
+    ///     let _buf = builder.close()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn extend_from_locations<I>(&mut self, locations: I) -> Result<()>
+    where
+        I: IntoIterator<Item = (Url, Option<OffsetDateTime>)>,
+    {
+        for record in locations {
+            self.write(&Index::from(record))?;
+        }
+
+        Ok(())
+    }
+}
+
 impl<W: Write> Builder<W, Index> for IndexBuilder<W> {
     type Error = Error;
 
     fn new(writer: W) -> Result<Self> {
-        let mut this = Self::from_writer(writer);
-        let temp = this.create_index_open()?;
-        this.inner.writer.write_all(&temp)?;
-        Ok(this)
+        Ok(Self::from_writer(writer))
     }
 
     fn write(&mut self, record: &Index) -> Result<()> {
+        let temp = self.create_index_open()?;
+        self.inner.writer.write_all(&temp)?;
         let temp = self.create_index_record(record)?;
         self.inner.writer.write_all(&temp)?;
         self.inner.records += 1;
@@ -137,8 +244,11 @@ impl<W: Write> Builder<W, Index> for IndexBuilder<W> {
     }
 
     fn close(mut self) -> Result<W> {
+        let temp = self.create_index_open()?;
+        self.inner.writer.write_all(&temp)?;
         let temp = self.create_index_close()?;
         self.inner.writer.write_all(&temp)?;
+        self.inner.writer.flush()?;
         Ok(self.into_inner())
     }
 }
@@ -147,24 +257,47 @@ impl<W: Write> Builder<W, Index> for IndexBuilder<W> {
 #[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
 mod tokio {
     use async_trait::async_trait;
+    use time::OffsetDateTime;
     use tokio::io::{AsyncWrite, AsyncWriteExt};
+    use url::Url;
 
-    use crate::build::{AsyncBuilder, IndexBuilder};
+    use crate::build::{AsyncBuilder, IndexBuilder, InnerBuilder};
     use crate::record::Index;
     use crate::{Error, Result};
 
+    impl<W: AsyncWrite + Unpin + Send> IndexBuilder<W> {
+        /// Creates a new instance with the given writer, that indents nested elements.
+        /// See [`IndexBuilder::new_pretty`].
+        pub async fn new_pretty_async(writer: W) -> Result<Self> {
+            let inner = InnerBuilder::from_writer(writer).with_pretty(true);
+            Ok(Self::from_inner(inner))
+        }
+
+        /// Writes a record for every location with the optional modify timestamp.
+        /// See [`IndexBuilder::extend_from_locations`].
+        pub async fn extend_from_locations_async<I>(&mut self, locations: I) -> Result<()>
+        where
+            I: IntoIterator<Item = (Url, Option<OffsetDateTime>)>,
+        {
+            for record in locations {
+                self.write(&Index::from(record)).await?;
+            }
+
+            Ok(())
+        }
+    }
+
     #[async_trait]
     impl<W: AsyncWrite + Unpin + Send> AsyncBuilder<W, Index> for IndexBuilder<W> {
         type Error = Error;
 
         async fn new(writer: W) -> Result<Self> {
-            let mut this = Self::from_writer(writer);
-            let temp = this.create_index_open()?;
-            this.inner.writer.write_all(&temp).await?;
-            Ok(this)
+            Ok(Self::from_writer(writer))
         }
 
         async fn write(&mut self, record: &Index) -> Result<()> {
+            let temp = self.create_index_open()?;
+            self.inner.writer.write_all(&temp).await?;
             let temp = self.create_index_record(record)?;
             self.inner.writer.write_all(&temp).await?;
             self.inner.records += 1;
@@ -172,9 +305,42 @@ mod tokio {
         }
 
         async fn close(mut self) -> Result<W> {
+            let temp = self.create_index_open()?;
+            self.inner.writer.write_all(&temp).await?;
             let temp = self.create_index_close()?;
             self.inner.writer.write_all(&temp).await?;
+            self.inner.writer.flush().await?;
             Ok(self.into_inner())
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use time::macros::datetime;
+    use url::Url;
+
+    use crate::build::{Builder, IndexBuilder};
+    use crate::parse::{IndexParser, Parser};
+    use crate::Result;
+
+    #[test]
+    fn synk_extend() -> Result<()> {
+        let a = Url::parse("https://example.com/sitemap_1.xml").unwrap();
+        let b = Url::parse("https://example.com/sitemap_2.xml").unwrap();
+        let modified = datetime!(2020-01-01 0:00 UTC);
+
+        let mut builder = IndexBuilder::new(Vec::new())?;
+        builder.extend_from_locations([(a.clone(), Some(modified)), (b.clone(), None)])?;
+        let buf = builder.close()?;
+
+        let mut parser = IndexParser::new(buf.as_slice())?;
+        let record = parser.read()?.unwrap();
+        assert_eq!((record.location, record.modified), (a, Some(modified)));
+        let record = parser.read()?.unwrap();
+        assert_eq!((record.location, record.modified), (b, None));
+        assert!(parser.read()?.is_none());
+
+        Ok(())
+    }
+}
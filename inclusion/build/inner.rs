@@ -2,8 +2,10 @@ use std::{marker::PhantomData, num::NonZeroU8};
 
 use countio::Counter;
 use quick_xml::{events, Writer};
-use time::format_description::well_known::iso8601;
+use time::format_description::well_known::{iso8601, Iso8601};
+use time::{OffsetDateTime, Time};
 
+use crate::record::{BYTE_LIMIT, XMLNS, XSI_SCHEMA_LOCATION, XSI_XMLNS};
 use crate::Error;
 
 pub(crate) const CONFIG: iso8601::EncodedConfig = iso8601::Config::DEFAULT
@@ -12,10 +14,19 @@ pub(crate) const CONFIG: iso8601::EncodedConfig = iso8601::Config::DEFAULT
     })
     .encode();
 
+/// Amount of spaces per nesting level of the pretty-printed output.
+pub(crate) const INDENT_SIZE: usize = 4;
+
 pub(crate) struct InnerBuilder<W, D> {
     pub(crate) record: PhantomData<D>,
     pub(crate) writer: Counter<W>,
     pub(crate) records: usize,
+    pub(crate) pretty: bool,
+    pub(crate) bom: bool,
+    pub(crate) declaration: bool,
+    pub(crate) opened: bool,
+    pub(crate) date_only: bool,
+    pub(crate) schema_location: bool,
 }
 
 impl<W, D> InnerBuilder<W, D> {
@@ -25,9 +36,57 @@ impl<W, D> InnerBuilder<W, D> {
             record: PhantomData,
             writer: Counter::new(writer),
             records: 0,
+            pretty: false,
+            bom: true,
+            declaration: true,
+            opened: false,
+            date_only: false,
+            schema_location: false,
         }
     }
 
+    /// Enables or disables indentation of nested elements.
+    pub fn with_pretty(mut self, pretty: bool) -> Self {
+        self.pretty = pretty;
+        self
+    }
+
+    /// Enables or disables the leading byte order mark.
+    pub fn with_bom(mut self, bom: bool) -> Self {
+        self.bom = bom;
+        self
+    }
+
+    /// Enables or disables the leading XML declaration.
+    pub fn with_declaration(mut self, declaration: bool) -> Self {
+        self.declaration = declaration;
+        self
+    }
+
+    /// Enables or disables date-only `lastmod` for midnight UTC timestamps.
+    pub fn with_date_only(mut self, date_only: bool) -> Self {
+        self.date_only = date_only;
+        self
+    }
+
+    /// Enables or disables the `xsi:schemaLocation` attribute of the root tag.
+    pub fn with_schema_location(mut self, schema_location: bool) -> Self {
+        self.schema_location = schema_location;
+        self
+    }
+
+    /// Formats the `lastmod` timestamp, omitting the time component if
+    /// enabled and the timestamp is midnight UTC.
+    pub fn format_modified(&self, modified: OffsetDateTime) -> String {
+        let midnight = modified.offset().is_utc() && modified.time() == Time::MIDNIGHT;
+        let formatted = match self.date_only && midnight {
+            true => modified.date().format(&Iso8601::DATE),
+            false => modified.format(&Iso8601::<{ CONFIG }>),
+        };
+
+        formatted.expect("should be formattable")
+    }
+
     /// Returns a reference to the underlying writer.
     pub fn get_ref(&self) -> &W {
         self.writer.get_ref()
@@ -43,34 +102,85 @@ impl<W, D> InnerBuilder<W, D> {
         self.writer.into_inner()
     }
 
-    pub fn create_open_tag(&mut self, tag: &str) -> Result<Vec<u8>, Error> {
-        let mut temp = Writer::new(Vec::new());
-        temp.write_bom()?;
+    /// Creates a new temporary writer, indented if enabled.
+    fn create_writer(&self) -> Writer<Vec<u8>> {
+        match self.pretty {
+            true => Writer::new_with_indent(Vec::new(), b' ', INDENT_SIZE),
+            false => Writer::new(Vec::new()),
+        }
+    }
+
+    /// Writes the prolog and the root tag, unless already written.
+    /// The schema is only written if the schema location is enabled.
+    pub fn create_open_tag(&mut self, tag: &str, schema: &str) -> Result<Vec<u8>, Error> {
+        if self.opened {
+            return Ok(Vec::new());
+        }
+
+        self.opened = true;
+        let mut temp = self.create_writer();
+        if self.bom {
+            temp.write_bom()?;
+        }
 
         // <?xml version="1.0" encoding="UTF-8"?>
-        let decl = events::BytesDecl::new("1.0", Some("UTF-8"), None);
-        temp.write_event(events::Event::Decl(decl))?;
+        if self.declaration {
+            let decl = events::BytesDecl::new("1.0", Some("UTF-8"), None);
+            temp.write_event(events::Event::Decl(decl))?;
+        }
 
         // <urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
         // <sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
-        const XMLNS: [(&str, &str); 1] = [("xmlns", "http://www.sitemaps.org/schemas/sitemap/0.9")];
-
         let tag = events::BytesStart::new(tag);
-        let tag = tag.with_attributes(XMLNS);
+        let tag = tag.with_attributes([XMLNS]);
+        let tag = match self.schema_location {
+            true => tag.with_attributes([XSI_XMLNS, (XSI_SCHEMA_LOCATION, schema)]),
+            false => tag,
+        };
         temp.write_event(events::Event::Start(tag))?;
 
         Ok(temp.into_inner())
     }
 
+    /// Writes the record as if nested in the root tag, so the indentation is preserved.
+    ///
+    /// Enforces the byte limit against already written bytes (including the
+    /// prolog and the root tag) and reserves bytes of the closing root tag.
+    pub fn create_record<F>(&mut self, tag: &str, apply: F) -> Result<Vec<u8>, Error>
+    where
+        F: FnOnce(&mut Writer<Vec<u8>>) -> quick_xml::Result<()>,
+    {
+        let mut temp = self.create_writer();
+        temp.write_event(events::Event::Start(events::BytesStart::new(tag)))?;
+        let skip = temp.get_ref().len();
+        apply(&mut temp)?;
+
+        let mut buf = temp.into_inner();
+        buf.drain(..skip);
+
+        let close = self.create_close_tag(tag)?.len();
+        let total = self.writer.writer_bytes() + buf.len() + close;
+        if total > BYTE_LIMIT {
+            let over_limit = total - BYTE_LIMIT;
+            return Err(Error::ByteLimit { over: over_limit });
+        }
+
+        Ok(buf)
+    }
+
     pub fn create_close_tag(&mut self, tag: &str) -> Result<Vec<u8>, Error> {
-        let mut temp = Writer::new(Vec::new());
+        let mut temp = self.create_writer();
+        temp.write_event(events::Event::Start(events::BytesStart::new(tag)))?;
+        let skip = temp.get_ref().len();
 
         // </urlset>
         // </sitemapindex>
         let tag = events::BytesEnd::new(tag);
         temp.write_event(events::Event::End(tag))?;
 
-        Ok(temp.into_inner())
+        let mut buf = temp.into_inner();
+        buf.drain(..skip);
+        Ok(buf)
     }
 }
 
@@ -79,6 +189,10 @@ impl<W, D> std::fmt::Debug for InnerBuilder<W, D> {
         f.debug_struct("XmlBuilder")
             .field("bytes", &self.writer.writer_bytes())
             .field("records", &self.records)
+            .field("pretty", &self.pretty)
+            .field("bom", &self.bom)
+            .field("declaration", &self.declaration)
+            .field("date_only", &self.date_only)
             .finish()
     }
 }
@@ -3,12 +3,16 @@ mod entry;
 mod index;
 mod inner;
 mod plain;
+mod sorted;
+mod string;
 
 pub use auto::*;
 pub use entry::*;
 pub use index::*;
 pub(crate) use inner::*;
 pub use plain::*;
+pub use sorted::*;
+pub use string::*;
 
 // TODO: Make builders take BufWrite.
 
@@ -22,7 +26,7 @@ pub trait Builder<W: std::io::Write, D>: Sized {
     /// Writes another record into the underlying writer.
     fn write(&mut self, record: &D) -> Result<(), Self::Error>;
 
-    /// Closes tags if needed and releases the writer.
+    /// Closes tags if needed, flushes and releases the writer.
     fn close(self) -> Result<W, Self::Error>;
 }
 
@@ -39,6 +43,6 @@ pub trait AsyncBuilder<W: tokio::io::AsyncWrite, D>: Sized {
     /// Writes another record into the underlying writer.
     async fn write(&mut self, record: &D) -> Result<(), Self::Error>;
 
-    /// Closes tags if needed and releases the writer.
+    /// Closes tags if needed, flushes and releases the writer.
     async fn close(self) -> Result<W, Self::Error>;
 }
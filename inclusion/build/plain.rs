@@ -7,6 +7,26 @@ use crate::build::Builder;
 use crate::record::*;
 use crate::{Error, Result};
 
+/// Line terminator used by the [PlainBuilder].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LineEnding {
+    /// Unix-style `\n` terminator.
+    #[default]
+    Lf,
+    /// Windows-style `\r\n` terminator.
+    CrLf,
+}
+
+impl LineEnding {
+    /// Returns the line terminator as a string slice.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Lf => "\n",
+            Self::CrLf => "\r\n",
+        }
+    }
+}
+
 /// Sitemap builder for the simple TXT file that contains one URL per line.
 ///
 /// For example:
@@ -16,7 +36,7 @@ use crate::{Error, Result};
 /// https://www.example.com/file2.html
 /// ```
 ///
-/// Enforces [total written/read bytes](BYTE_LIMIT) and [total records](RECORD_LIMIT) limits.
+/// Enforces [total written/read bytes](BYTE_LIMIT), [total records](RECORD_LIMIT) and [url length](URL_LEN_LIMIT) limits.
 /// See [Error].
 ///
 /// ```rust
@@ -35,9 +55,31 @@ use crate::{Error, Result};
 pub struct PlainBuilder<W> {
     writer: Counter<W>,
     records: usize,
+    line_ending: LineEnding,
 }
 
 impl<W> PlainBuilder<W> {
+    /// Sets the line terminator of all subsequently written records.
+    ///
+    /// ```rust
+    /// use sitemapo::build::{Builder, LineEnding, PlainBuilder};
+    ///
+    /// fn main() -> sitemapo::Result<()> {
+    ///     let buf = Vec::new();
+    ///     let rec = "https://example.com/".try_into().unwrap();
+    ///
+    ///     let mut builder = PlainBuilder::new(buf)?.with_line_ending(LineEnding::CrLf);
+    ///     builder.write(&rec)?;
+    ///     let buf = builder.close()?;
+    ///     assert_eq!(buf, b"https://example.com/\r\n");
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.line_ending = line_ending;
+        self
+    }
+
     /// Returns a reference to the underlying writer.
     pub fn get_ref(&self) -> &W {
         self.writer.get_ref()
@@ -60,25 +102,30 @@ impl<W> PlainBuilder<W> {
         Self {
             writer: Counter::new(writer),
             records: 0,
+            line_ending: LineEnding::default(),
         }
     }
 
     pub(crate) fn create_next_line(&mut self, url: &Url) -> Result<Vec<u8>> {
-        const NEWLINE: &str = "\n";
+        let newline = self.line_ending.as_str();
 
         if self.records + 1 > RECORD_LIMIT {
             return Err(Error::EntryLimit { over: 1 });
         }
 
         let record = url.to_string();
-        let record_bytes = record.len() + NEWLINE.len();
+        let record_bytes = record.len() + newline.len();
+        if record_bytes > URL_LEN_LIMIT {
+            return Err(Error::UrlLimit { len: record.len() });
+        }
+
         let total_bytes = self.writer.writer_bytes() + record_bytes;
         if total_bytes > BYTE_LIMIT {
             let over_limit = total_bytes - BYTE_LIMIT;
             return Err(Error::ByteLimit { over: over_limit });
         }
 
-        Ok((record + NEWLINE).into_bytes())
+        Ok((record + newline).into_bytes())
     }
 }
 
@@ -96,7 +143,8 @@ impl<W: Write> Builder<W, Url> for PlainBuilder<W> {
         Ok(())
     }
 
-    fn close(self) -> Result<W> {
+    fn close(mut self) -> Result<W> {
+        self.writer.flush()?;
         Ok(self.into_inner())
     }
 }
@@ -106,6 +154,7 @@ impl<W> std::fmt::Debug for PlainBuilder<W> {
         f.debug_struct("TxtBuilder")
             .field("bytes", &self.writer.writer_bytes())
             .field("records", &self.records)
+            .field("line_ending", &self.line_ending)
             .finish()
     }
 }
@@ -135,7 +184,8 @@ mod tokio {
             Ok(())
         }
 
-        async fn close(self) -> Result<W> {
+        async fn close(mut self) -> Result<W> {
+            self.writer.flush().await?;
             Ok(self.into_inner())
         }
     }
@@ -146,7 +196,7 @@ mod test {
     use std::io::BufWriter;
     use url::Url;
 
-    use crate::build::{Builder, PlainBuilder};
+    use crate::build::{Builder, LineEnding, PlainBuilder};
     use crate::Result;
 
     #[test]
@@ -179,12 +229,66 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn synk_close_flush() -> Result<()> {
+        let buf = BufWriter::new(Vec::new());
+        let mut builder = PlainBuilder::new(buf)?;
+
+        let url = Url::parse("https://example.com/").unwrap();
+        builder.write(&url)?;
+        let buf = builder.close()?;
+
+        let exp = String::from_utf8(buf.get_ref().clone()).unwrap();
+        assert_eq!(url.to_string() + "\n", exp);
+
+        Ok(())
+    }
+
+    #[test]
+    fn synk_url_limit() {
+        use crate::Error;
+
+        let buf = Vec::new();
+        let mut builder = PlainBuilder::new(buf).unwrap();
+
+        let path = "a".repeat(70 * 1024);
+        let url = Url::parse(&format!("https://example.com/{path}")).unwrap();
+        let rs = builder.write(&url);
+        assert!(matches!(rs, Err(Error::UrlLimit { .. })));
+
+        let buf = builder.close().unwrap();
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn synk_crlf() -> Result<()> {
+        use crate::parse::{Parser, PlainParser};
+
+        let buf = Vec::new();
+        let mut builder = PlainBuilder::new(buf)?.with_line_ending(LineEnding::CrLf);
+
+        let url = Url::parse("https://example.com/").unwrap();
+        builder.write(&url)?;
+        builder.write(&url)?;
+        let buf = builder.close()?;
+
+        let exp = String::from_utf8(buf.clone()).unwrap();
+        assert_eq!(format!("{url}\r\n{url}\r\n"), exp);
+
+        let mut parser = PlainParser::new(buf.as_slice())?;
+        assert_eq!(parser.read()?, Some(url.clone()));
+        assert_eq!(parser.read()?, Some(url));
+        assert_eq!(parser.read()?, None);
+
+        Ok(())
+    }
 }
 
 #[cfg(feature = "tokio")]
 #[cfg(test)]
 mod tokio_test {
-    use tokio::io::{AsyncWriteExt, BufWriter};
+    use tokio::io::BufWriter;
     use url::Url;
 
     use crate::build::{AsyncBuilder, PlainBuilder};
@@ -212,10 +316,10 @@ mod tokio_test {
 
         let url = Url::parse("https://example.com/").unwrap();
         builder.write(&url).await?;
-        let mut buf = builder.close().await?;
+        let buf = builder.close().await?;
 
-        let _ = buf.flush().await?;
-        let buf = buf.into_inner();
+        // Closing the builder flushes the buffer.
+        let buf = buf.get_ref().clone();
         let exp = String::from_utf8(buf);
         assert_eq!(Ok(url.to_string() + "\n"), exp);
 
@@ -0,0 +1,311 @@
+use std::io::Write;
+
+use crate::build::{Builder, EntryBuilder};
+use crate::record::Entry;
+use crate::{Error, Result};
+
+/// Sitemap builder for the versatile XML file, that writes entries sorted
+/// by their location within the bounded buffer.
+///
+/// Buffers up to the capacity entries (1024 by default), sorts and writes
+/// them once the buffer is full, so the output is only sorted within each
+/// buffer window. Remaining entries are written on [`Builder::close`].
+/// Limits are enforced when entries are written, not buffered.
+/// See [`EntryBuilder`].
+///
+/// Note that errors are deferred: a failed write is returned from the later
+/// call that flushes the buffer, so it may concern a different entry.
+/// Entries that were not written stay buffered and are retried next time.
+///
+/// ```rust
+/// use sitemapo::build::{Builder, SortedEntryBuilder};
+/// use sitemapo::record::Entry;
+///
+/// fn main() -> sitemapo::Result<()> {
+///     let buf = Vec::new();
+///     let mut builder = SortedEntryBuilder::new(buf)?.with_capacity(16);
+///
+///     for path in ["/b.html", "/a.html"] {
+///         let url = format!("https://example.com{path}").as_str().try_into().unwrap();
+///         builder.write(&Entry::new(url))?;
+///     }
+///
+///     let _buf = builder.close()?;
+///     Ok(())
+/// }
+/// ```
+pub struct SortedEntryBuilder<W> {
+    builder: EntryBuilder<W>,
+    buffer: Vec<Entry>,
+    capacity: usize,
+}
+
+impl<W> SortedEntryBuilder<W> {
+    const DEFAULT_CAPACITY: usize = 1024;
+
+    /// Creates a new instance with the given entry builder.
+    pub(crate) fn from_builder(builder: EntryBuilder<W>) -> Self {
+        Self {
+            builder,
+            buffer: Vec::new(),
+            capacity: Self::DEFAULT_CAPACITY,
+        }
+    }
+
+    /// Sets the maximal amount of buffered entries, at least one.
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+
+    /// Returns a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        self.builder.get_ref()
+    }
+
+    /// Returns a mutable reference to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        self.builder.get_mut()
+    }
+
+    /// Returns an underlying writer, discarding buffered entries.
+    pub fn into_inner(self) -> W {
+        self.builder.into_inner()
+    }
+
+    /// Buffers the entry, returns `true` if the buffer is full.
+    fn push(&mut self, record: &Entry) -> bool {
+        self.buffer.push(record.clone());
+        self.buffer.len() >= self.capacity
+    }
+
+    /// Sorts the buffered entries by their location.
+    fn sort(&mut self) {
+        self.buffer.sort_by(|a, b| a.location.cmp(&b.location));
+    }
+}
+
+impl<W> std::fmt::Debug for SortedEntryBuilder<W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SortedEntryBuilder")
+            .field("builder", &self.builder)
+            .field("buffered", &self.buffer.len())
+            .field("capacity", &self.capacity)
+            .finish()
+    }
+}
+
+impl<W: Write> SortedEntryBuilder<W> {
+    fn flush_sync(&mut self) -> Result<()> {
+        self.sort();
+
+        // Removes only written entries, keeping the rest on failure.
+        let mut written = 0;
+        let mut result = Ok(());
+        for record in self.buffer.iter() {
+            result = self.builder.write(record);
+            if result.is_err() {
+                break;
+            }
+
+            written += 1;
+        }
+
+        self.buffer.drain(..written);
+        result
+    }
+}
+
+impl<W: Write> Builder<W, Entry> for SortedEntryBuilder<W> {
+    type Error = Error;
+
+    fn new(writer: W) -> Result<Self> {
+        let builder = EntryBuilder::new(writer)?;
+        Ok(Self::from_builder(builder))
+    }
+
+    fn write(&mut self, record: &Entry) -> Result<()> {
+        if self.push(record) {
+            self.flush_sync()?;
+        }
+
+        Ok(())
+    }
+
+    fn close(mut self) -> Result<W> {
+        self.flush_sync()?;
+        self.builder.close()
+    }
+}
+
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+mod tokio {
+    use async_trait::async_trait;
+    use tokio::io::AsyncWrite;
+
+    use crate::build::{AsyncBuilder, EntryBuilder, SortedEntryBuilder};
+    use crate::record::Entry;
+    use crate::{Error, Result};
+
+    impl<W: AsyncWrite + Unpin + Send> SortedEntryBuilder<W> {
+        async fn flush_async(&mut self) -> Result<()> {
+            self.sort();
+
+            // Removes only written entries, keeping the rest on failure.
+            let mut written = 0;
+            let mut result = Ok(());
+            for record in self.buffer.iter() {
+                result = self.builder.write(record).await;
+                if result.is_err() {
+                    break;
+                }
+
+                written += 1;
+            }
+
+            self.buffer.drain(..written);
+            result
+        }
+    }
+
+    #[async_trait]
+    impl<W: AsyncWrite + Unpin + Send> AsyncBuilder<W, Entry> for SortedEntryBuilder<W> {
+        type Error = Error;
+
+        async fn new(writer: W) -> Result<Self> {
+            let builder = EntryBuilder::new(writer).await?;
+            Ok(Self::from_builder(builder))
+        }
+
+        async fn write(&mut self, record: &Entry) -> Result<()> {
+            if self.push(record) {
+                self.flush_async().await?;
+            }
+
+            Ok(())
+        }
+
+        async fn close(mut self) -> Result<W> {
+            self.flush_async().await?;
+            self.builder.close().await
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use url::Url;
+
+    use crate::build::SortedEntryBuilder;
+    use crate::record::Entry;
+    use crate::Result;
+
+    const PATHS: [&str; 5] = ["/3", "/1", "/2", "/5", "/4"];
+
+    fn paths(buf: &[u8]) -> Result<Vec<String>> {
+        use crate::parse::{EntryParser, Parser};
+
+        let mut parser = EntryParser::new(buf)?;
+        let mut paths = Vec::new();
+        while let Some(record) = parser.read()? {
+            paths.push(record.location.path().to_string());
+        }
+
+        Ok(paths)
+    }
+
+    fn entry(path: &str) -> Entry {
+        let url = Url::parse("https://example.com/").unwrap();
+        Entry::new(url.join(path).unwrap())
+    }
+
+    #[test]
+    fn synk() -> Result<()> {
+        use crate::build::Builder;
+
+        let mut builder = SortedEntryBuilder::new(Vec::new())?.with_capacity(3);
+        for path in PATHS {
+            builder.write(&entry(path))?;
+        }
+
+        let buf = builder.close()?;
+        assert_eq!(paths(&buf)?, ["/1", "/2", "/3", "/4", "/5"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn synk_window() -> Result<()> {
+        use crate::build::Builder;
+
+        let mut builder = SortedEntryBuilder::new(Vec::new())?.with_capacity(2);
+        for path in PATHS {
+            builder.write(&entry(path))?;
+        }
+
+        let buf = builder.close()?;
+        assert_eq!(paths(&buf)?, ["/1", "/3", "/2", "/5", "/4"]);
+
+        Ok(())
+    }
+
+    /// Fails every write while the flag is set.
+    struct FailingWriter {
+        buf: Vec<u8>,
+        fail: bool,
+    }
+
+    impl std::io::Write for FailingWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            match self.fail {
+                true => Err(std::io::ErrorKind::Other.into()),
+                false => self.buf.write(buf),
+            }
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn synk_deferred_error() -> Result<()> {
+        use crate::build::Builder;
+
+        let writer = FailingWriter {
+            buf: Vec::new(),
+            fail: false,
+        };
+        let mut builder = SortedEntryBuilder::new(writer)?.with_capacity(2);
+        builder.write(&entry("/5"))?;
+        builder.write(&entry("/4"))?;
+
+        builder.get_mut().fail = true;
+        builder.write(&entry("/3"))?;
+        assert!(builder.write(&entry("/1")).is_err());
+
+        builder.get_mut().fail = false;
+        let writer = builder.close()?;
+        assert_eq!(paths(&writer.buf)?, ["/4", "/5", "/1", "/3"]);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn asynk() -> Result<()> {
+        use crate::build::AsyncBuilder;
+
+        let builder = SortedEntryBuilder::new(Vec::new()).await?;
+        let mut builder = builder.with_capacity(3);
+        for path in PATHS {
+            builder.write(&entry(path)).await?;
+        }
+
+        let buf = builder.close().await?;
+        assert_eq!(paths(&buf)?, ["/1", "/2", "/3", "/4", "/5"]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,135 @@
+use std::fmt;
+use std::io::{Error, ErrorKind, Result, Write};
+
+/// The adapter that enables builders to write into any [`fmt::Write`],
+/// e.g. [`String`], as the output is always valid UTF-8.
+///
+/// ```rust
+/// use sitemapo::build::{Builder, EntryBuilder, FmtWriter};
+/// use sitemapo::record::Entry;
+///
+/// fn main() -> sitemapo::Result<()> {
+///     let buf = FmtWriter::new(String::new());
+///     let url = "https://example.com/".try_into().unwrap();
+///     let rec = Entry::new(url);
+///
+///     let mut builder = EntryBuilder::new(buf)?;
+///     builder.write(&rec)?;
+///     let buf: String = builder.close()?.into_inner();
+///     assert!(buf.contains("<loc>https://example.com/</loc>"));
+///     Ok(())
+/// }
+/// ```
+pub struct FmtWriter<W> {
+    inner: W,
+    // Trailing bytes of the incomplete UTF-8 sequence.
+    pending: Vec<u8>,
+}
+
+impl<W> FmtWriter<W> {
+    /// Creates a new instance with the given writer.
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Returns a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns an underlying writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: fmt::Write> Write for FmtWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        self.pending.extend_from_slice(buf);
+        let valid = match std::str::from_utf8(&self.pending) {
+            Ok(text) => text.len(),
+            // Incomplete sequence, the rest is expected with the next write.
+            Err(e) if e.error_len().is_none() => e.valid_up_to(),
+            Err(e) => {
+                self.pending.truncate(self.pending.len() - buf.len());
+                return Err(Error::new(ErrorKind::InvalidData, e));
+            }
+        };
+
+        let text = std::str::from_utf8(&self.pending[..valid]).expect("should be valid");
+        self.inner.write_str(text).map_err(Error::other)?;
+
+        self.pending.drain(..valid);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<W> From<W> for FmtWriter<W> {
+    fn from(inner: W) -> Self {
+        Self::new(inner)
+    }
+}
+
+impl<W: fmt::Debug> fmt::Debug for FmtWriter<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FmtWriter")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Write;
+
+    use url::Url;
+
+    use crate::build::{Builder, EntryBuilder, FmtWriter};
+    use crate::parse::{EntryParser, Parser};
+    use crate::record::Entry;
+    use crate::Result;
+
+    #[test]
+    fn split() -> std::io::Result<()> {
+        let text = "ü".as_bytes();
+        let mut writer = FmtWriter::new(String::new());
+        writer.write_all(&text[..1])?;
+        writer.write_all(&text[1..])?;
+        assert_eq!(writer.into_inner(), "ü");
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        let mut writer = FmtWriter::new(String::new());
+        assert!(writer.write_all(b"\xff").is_err());
+    }
+
+    #[test]
+    fn roundtrip() -> Result<()> {
+        let buf = FmtWriter::new(String::new());
+        let mut builder = EntryBuilder::new(buf)?;
+
+        let url = Url::parse("https://example.com/").unwrap();
+        builder.write(&Entry::new(url.clone()))?;
+        let buf = builder.close()?.into_inner();
+
+        let mut parser = EntryParser::new(buf.as_bytes())?;
+        let record = parser.read()?.unwrap();
+        assert_eq!(record.location, url);
+
+        Ok(())
+    }
+}
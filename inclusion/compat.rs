@@ -0,0 +1,61 @@
+//! Parsers & builders are implemented over `tokio::io` traits, as the XML
+//! event reader relies on the `quick_xml`'s tokio-specific async support.
+//! Readers & writers implementing `futures::io` traits (e.g. from `async-std`
+//! or `smol`) are wrapped into the [`Compat`] adapter instead.
+//!
+//! ```rust
+//! use sitemapo::compat::FuturesAsyncReadCompatExt;
+//! use sitemapo::parse::{AsyncParser, PlainParser};
+//!
+//! #[tokio::main(flavor = "current_thread")]
+//! async fn main() -> sitemapo::Result<()> {
+//!     let buf = "https://example.com/file1.html\n".as_bytes();
+//!     let mut parser = PlainParser::new(buf.compat()).await?;
+//!     assert!(parser.read().await?.is_some());
+//!
+//!     // Releases the `futures::io` reader.
+//!     let _ = parser.close().await?.into_inner();
+//!     Ok(())
+//! }
+//! ```
+
+pub use tokio_util::compat::{Compat, FuturesAsyncReadCompatExt, FuturesAsyncWriteCompatExt};
+
+/// [`AsyncParser`] over the `futures::io` reader.
+///
+/// [`AsyncParser`]: crate::parse::AsyncParser
+pub type FuturesReader<R> = Compat<R>;
+
+/// [`AsyncBuilder`] over the `futures::io` writer.
+///
+/// [`AsyncBuilder`]: crate::build::AsyncBuilder
+pub type FuturesWriter<W> = Compat<W>;
+
+#[cfg(test)]
+mod test {
+    use url::Url;
+
+    use super::{FuturesAsyncReadCompatExt, FuturesAsyncWriteCompatExt};
+    use crate::build::{AsyncBuilder, EntryBuilder};
+    use crate::parse::{AsyncParser, EntryParser};
+    use crate::record::Entry;
+    use crate::Result;
+
+    #[tokio::test]
+    async fn asynk_roundtrip() -> Result<()> {
+        let url = Url::parse("https://example.com/").unwrap();
+
+        // Vec<u8> implements futures::io::AsyncWrite.
+        let mut builder = EntryBuilder::new(Vec::new().compat_write()).await?;
+        builder.write(&Entry::new(url.clone())).await?;
+        let buf = builder.close().await?.into_inner();
+
+        // &[u8] implements futures::io::AsyncBufRead.
+        let mut parser = EntryParser::new(buf.as_slice().compat()).await?;
+        let entry = parser.read().await?.unwrap();
+        assert_eq!(entry.location, url);
+        assert!(parser.read().await?.is_none());
+
+        Ok(())
+    }
+}
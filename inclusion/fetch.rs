@@ -0,0 +1,111 @@
+use bytes::Bytes;
+use futures_util::{future::BoxFuture, stream::BoxStream, StreamExt, TryStreamExt};
+use tokio_util::io::StreamReader;
+use url::Url;
+
+use crate::{Error, Result};
+
+/// Streamed body of the fetched sitemap.
+/// See [`reqwest_fetcher`].
+pub type ReqwestReader = StreamReader<BoxStream<'static, std::io::Result<Bytes>>, Bytes>;
+
+/// Creates a new fetcher for the [`AutoParser::try_async`], that sends
+/// `GET` requests with the given client and streams the response body.
+///
+/// Non-successful (i.e. non-2xx) responses result in the [`Error::Status`].
+/// Note that the `https` support requires one of the `reqwest` TLS features.
+///
+/// ```rust,no_run
+/// use sitemapo::{fetch::reqwest_fetcher, parse::AutoParser, url::Url};
+///
+/// #[tokio::main(flavor = "current_thread")]
+/// async fn main() -> Result<(), sitemapo::Error> {
+///     let fetcher = reqwest_fetcher(reqwest::Client::new());
+///
+///     // Sitemaps listed in the robots.txt file.
+///     let sitemaps = [Url::parse("http://example.com/sitemap.xml").unwrap()];
+///
+///     let mut parser = AutoParser::new(sitemaps);
+///     while let Some(_record) = parser.try_async(&fetcher).await? {
+///         // ..
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [`AutoParser::try_async`]: crate::parse::AutoParser::try_async
+pub fn reqwest_fetcher(
+    client: reqwest::Client,
+) -> impl Fn(Url) -> BoxFuture<'static, Result<ReqwestReader>> {
+    move |url| {
+        let client = client.clone();
+        Box::pin(async move {
+            let response = client.get(url).send().await?;
+            let status = response.status();
+            if !status.is_success() {
+                let code = status.as_u16();
+                return Err(Error::Status { code });
+            }
+
+            let stream = response.bytes_stream();
+            let stream = stream.map_err(std::io::Error::other).boxed();
+            Ok(StreamReader::new(stream))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    use super::*;
+    use crate::parse::AutoParser;
+
+    /// Serves the single connection with the given status line and body.
+    async fn serve(status: &'static str, body: &'static str) -> Url {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut buf = [0; 1024];
+            let _ = stream.read(&mut buf).await.unwrap();
+
+            let len = body.len();
+            let response = format!(
+                "HTTP/1.1 {status}\r\ncontent-length: {len}\r\nconnection: close\r\n\r\n{body}"
+            );
+
+            stream.write_all(response.as_bytes()).await.unwrap();
+        });
+
+        Url::parse(&format!("http://{addr}/sitemap.xml")).unwrap()
+    }
+
+    #[tokio::test]
+    async fn success() -> Result<()> {
+        let body = r#"<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+            <url><loc>https://example.com/file1.html</loc></url>
+        </urlset>"#;
+
+        let url = serve("200 OK", body).await;
+        let fetcher = reqwest_fetcher(reqwest::Client::new());
+        let mut parser = AutoParser::new([url]);
+
+        let record = parser.try_async(&fetcher).await?.unwrap();
+        assert_eq!(record.location.path(), "/file1.html");
+        assert!(parser.try_async(&fetcher).await?.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn status() {
+        let url = serve("404 Not Found", "").await;
+        let fetcher = reqwest_fetcher(reqwest::Client::new());
+        let reader = fetcher(url).await;
+        assert!(matches!(reader, Err(Error::Status { code: 404 })));
+    }
+}
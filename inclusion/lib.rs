@@ -21,6 +21,57 @@ pub enum Error {
     #[error("too many bytes: {over} bytes over limit")]
     ByteLimit { over: usize },
 
+    /// Parsers/builders enforce url length limit.
+    /// See [`URL_LEN_LIMIT`].
+    ///
+    /// [`URL_LEN_LIMIT`]: record::URL_LEN_LIMIT
+    #[error("too long url: {len} bytes")]
+    UrlLimit { len: usize },
+
+    /// Strict parsers expect the root element in the sitemap namespace.
+    /// See [`EntryParser::with_strict`].
+    ///
+    /// [`EntryParser::with_strict`]: parse::EntryParser::with_strict
+    #[error("unexpected namespace: `{found}`")]
+    Namespace { found: String },
+
+    /// Sitemap ended before the `urlset` or `sitemapindex` root element
+    /// e.g. empty or plain text input. See [`Scanner`].
+    ///
+    /// [`Scanner`]: parse::Scanner
+    #[error("missing root element")]
+    MissingRoot,
+
+    /// Fetched sitemap response has a non-successful status code.
+    /// See [`reqwest_fetcher`].
+    ///
+    /// [`reqwest_fetcher`]: fetch::reqwest_fetcher
+    #[cfg(feature = "reqwest")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "reqwest")))]
+    #[error("unexpected status code: {code}")]
+    Status { code: u16 },
+
+    /// HTTP client failure.
+    /// See [`reqwest::Error`].
+    #[cfg(feature = "reqwest")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "reqwest")))]
+    #[error("http error: {0}")]
+    Http(#[from] reqwest::Error),
+
+    /// Record was rejected by the custom validator.
+    /// See [`EntryBuilder::set_validator`].
+    ///
+    /// [`EntryBuilder::set_validator`]: build::EntryBuilder::set_validator
+    #[error("validation error: {0}")]
+    Validation(String),
+
+    /// Unable to create the `robots.txt` address.
+    /// See [`bootstrap`].
+    #[cfg(feature = "robots")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "robots")))]
+    #[error("robots error: {0}")]
+    Robots(#[from] robotxt::Error),
+
     /// Underlying reader/writer IO failure.
     /// See [`std::io::Error`].
     #[error("io error: {0}")]
@@ -39,10 +90,23 @@ pub enum Error {
 pub type Result<T> = std::result::Result<T, Error>;
 
 // Re-exports
+#[cfg(feature = "robots")]
+#[cfg_attr(docsrs, doc(cfg(feature = "robots")))]
+pub use bootstrap::bootstrap;
 pub use url;
 
+#[cfg(feature = "robots")]
+mod bootstrap;
 /// Builder types: `AutoBuilder`, `TxtBuilder` & `XmlBuilder`.
 pub mod build;
+/// Adapters for `futures::io` readers & writers.
+#[cfg(feature = "futures")]
+#[cfg_attr(docsrs, doc(cfg(feature = "futures")))]
+pub mod compat;
+/// Fetcher helpers for the `AutoParser`.
+#[cfg(feature = "reqwest")]
+#[cfg_attr(docsrs, doc(cfg(feature = "reqwest")))]
+pub mod fetch;
 /// Parser types: `AutoParser`, `TxtParser` & `XmlParser`.
 pub mod parse;
 /// Record and attribute types.
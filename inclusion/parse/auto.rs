@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use bytes::Bytes;
 use countio::Counter;
 use quick_xml::{events, Reader};
@@ -6,6 +8,29 @@ use url::Url;
 use crate::{parse::*, record::*, Error};
 
 /// Sitemap type resolver.
+///
+/// Detects the type of the single sitemap by its root element and creates
+/// the matching parser, so the sitemap can be parsed without the full
+/// [`AutoParser`], e.g. if sitemaps are fetched one at a time elsewhere.
+///
+/// Returns [`Error::MissingRoot`] if the input ends before the root element,
+/// e.g. if it's empty or the plain text sitemap.
+///
+/// ```rust
+/// use sitemapo::parse::Scanner;
+///
+/// fn main() -> sitemapo::Result<()> {
+///     let buf = r#"
+///         <urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+///             <url><loc>https://example.com/file1.html</loc></url>
+///         </urlset>
+///     "#.as_bytes();
+///
+///     let records = Scanner::from_sync(buf)?.read_all()?;
+///     assert_eq!(records.len(), 1);
+///     Ok(())
+/// }
+/// ```
 // TODO: Check for the plain txt sitemaps.
 pub enum Scanner<R> {
     Plain(PlainParser<R>),
@@ -14,51 +39,133 @@ pub enum Scanner<R> {
 }
 
 impl<R> Scanner<R> {
-    fn try_if_readable(reader: &Reader<Counter<R>>) -> Result<(), Error> {
-        try_if_readable(0, reader.get_ref().reader_bytes())
+    /// Overrides the total records and bytes limits of the detected parser.
+    /// See [`Limits`].
+    ///
+    /// Note that the root element is detected within the default limits.
+    pub fn with_limits(self, limits: Limits) -> Self {
+        match self {
+            Self::Plain(parser) => Self::Plain(parser.with_limits(limits)),
+            Self::Entry(parser) => Self::Entry(parser.with_limits(limits)),
+            Self::Index(parser) => Self::Index(parser.with_limits(limits)),
+        }
+    }
+
+    fn try_if_readable(reader: &Reader<Counter<R>>, limits: &Limits) -> Result<(), Error> {
+        limits.try_if_readable(0, reader.get_ref().reader_bytes())
     }
 
     /// Returns `Some(_)` is the opening tag was found, `bool` is true if the sitemap is an index.
-    fn is_xml_sitemap(event: events::Event) -> Option<bool> {
+    /// Validates the namespace of the opening tag in the strict mode.
+    /// Returns an error if the input ended before the opening tag.
+    fn is_xml_sitemap(event: events::Event, strict: bool) -> Result<Option<bool>, Error> {
+        if let events::Event::Eof = event {
+            return Err(Error::MissingRoot);
+        }
+
         if let events::Event::Start(bytes) = event {
             let name = bytes.name().into_inner();
-            if name.eq_ignore_ascii_case(SITEMAP_INDEX.as_bytes()) {
-                return Some(true);
+            let is_index = if name.eq_ignore_ascii_case(SITEMAP_INDEX.as_bytes()) {
+                true
             } else if name.eq_ignore_ascii_case(URL_SET.as_bytes()) {
-                return Some(false);
+                false
+            } else {
+                return Ok(None);
+            };
+
+            if strict {
+                try_if_namespace(&bytes)?;
             }
+
+            return Ok(Some(is_index));
         }
 
-        None
+        Ok(None)
     }
 
-    fn create_xml(is_index: bool, reader: Reader<Counter<R>>) -> Self {
-        let reader = reader.into_inner().into_inner();
+    fn create_xml(is_index: bool, reader: Reader<Counter<R>>, limits: Limits) -> Self {
+        // Keeps the reader state, so the root closing tag is matched.
         if is_index {
-            let mut reader = InnerParser::from_reader(reader);
+            let mut reader = InnerParser::from_xml_reader(reader);
             let bytes = Bytes::from(SITEMAP_INDEX.as_bytes().to_vec());
             reader.path = Vec::from([bytes]);
+            reader.limits = limits;
             Self::Index(IndexParser::from_inner(reader))
         } else {
-            let mut reader = InnerParser::from_reader(reader);
+            let mut reader = InnerParser::from_xml_reader(reader);
             let bytes = Bytes::from(URL_SET.as_bytes().to_vec());
             reader.path = Vec::from([bytes]);
+            reader.limits = limits;
             Self::Entry(EntryParser::from_inner(reader))
         }
     }
 }
 
+impl<R> Scanner<R> {
+    /// Converts the sitemap index record into the entry with the same
+    /// location and the modify timestamp.
+    fn index_entry(record: Index) -> Entry {
+        let entry = Entry::new(record.location);
+        match record.modified {
+            Some(modified) => entry.with_modified(modified),
+            None => entry,
+        }
+    }
+}
+
 impl<R: std::io::BufRead> Scanner<R> {
+    /// Reads all records of the detected sitemap, driving the inner parser.
+    ///
+    /// Records of the sitemap index are returned as entries with the location
+    /// of the referenced sitemaps, that are not fetched.
+    pub fn read_all(self) -> Result<Vec<Entry>, Error> {
+        let mut records = Vec::new();
+        match self {
+            Self::Plain(mut parser) => {
+                while let Some(record) = parser.read()? {
+                    records.push(record.into());
+                }
+            }
+            Self::Entry(mut parser) => {
+                while let Some(record) = parser.read()? {
+                    records.push(record);
+                }
+            }
+            Self::Index(mut parser) => {
+                while let Some(record) = parser.read()? {
+                    records.push(Self::index_entry(record));
+                }
+            }
+        }
+
+        Ok(records)
+    }
+
     /// Creates a new instance with the given reader.
     pub fn from_sync(reader: R) -> Result<Self, Error> {
+        Self::from_sync_with(reader, false)
+    }
+
+    /// Creates a new instance with the given reader, optionally in the strict mode.
+    /// See [`Error::Namespace`].
+    pub fn from_sync_with(reader: R, strict: bool) -> Result<Self, Error> {
+        Self::from_sync_limited(reader, strict, Limits::default())
+    }
+
+    /// Creates a new instance with the given reader and limits.
+    pub(crate) fn from_sync_limited(
+        reader: R,
+        strict: bool,
+        limits: Limits,
+    ) -> Result<Self, Error> {
         let mut reader = Reader::from_reader(Counter::new(reader));
         let mut buf = Vec::new();
 
         loop {
-            Self::try_if_readable(&reader)?;
+            Self::try_if_readable(&reader, &limits)?;
             let event = reader.read_event_into(&mut buf)?;
-            if let Some(is_index) = Self::is_xml_sitemap(event) {
-                return Ok(Self::create_xml(is_index, reader));
+            if let Some(is_index) = Self::is_xml_sitemap(event, strict)? {
+                return Ok(Self::create_xml(is_index, reader, limits));
             }
         }
     }
@@ -67,16 +174,56 @@ impl<R: std::io::BufRead> Scanner<R> {
 #[cfg(feature = "tokio")]
 #[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
 impl<R: tokio::io::AsyncBufRead + Unpin + Send> Scanner<R> {
+    /// Reads all records of the detected sitemap, driving the inner parser.
+    /// See [`Scanner::read_all`].
+    pub async fn read_all_async(self) -> Result<Vec<Entry>, Error> {
+        let mut records = Vec::new();
+        match self {
+            Self::Plain(mut parser) => {
+                while let Some(record) = parser.read().await? {
+                    records.push(record.into());
+                }
+            }
+            Self::Entry(mut parser) => {
+                while let Some(record) = parser.read().await? {
+                    records.push(record);
+                }
+            }
+            Self::Index(mut parser) => {
+                while let Some(record) = parser.read().await? {
+                    records.push(Self::index_entry(record));
+                }
+            }
+        }
+
+        Ok(records)
+    }
+
     /// Creates a new instance with the given reader.
     pub async fn from_async(reader: R) -> Result<Self, Error> {
+        Self::from_async_with(reader, false).await
+    }
+
+    /// Creates a new instance with the given reader, optionally in the strict mode.
+    /// See [`Error::Namespace`].
+    pub async fn from_async_with(reader: R, strict: bool) -> Result<Self, Error> {
+        Self::from_async_limited(reader, strict, Limits::default()).await
+    }
+
+    /// Creates a new instance with the given reader and limits.
+    pub(crate) async fn from_async_limited(
+        reader: R,
+        strict: bool,
+        limits: Limits,
+    ) -> Result<Self, Error> {
         let mut reader = Reader::from_reader(Counter::new(reader));
         let mut buf = Vec::new();
 
         loop {
-            Self::try_if_readable(&reader)?;
+            Self::try_if_readable(&reader, &limits)?;
             let event = reader.read_event_into_async(&mut buf).await?;
-            if let Some(is_index) = Self::is_xml_sitemap(event) {
-                return Ok(Self::create_xml(is_index, reader));
+            if let Some(is_index) = Self::is_xml_sitemap(event, strict)? {
+                return Ok(Self::create_xml(is_index, reader, limits));
             }
         }
     }
@@ -115,7 +262,11 @@ pub struct AutoParser<R> {
     sitemaps: Vec<Url>,
     plain: Option<PlainParser<R>>,
     entry: Option<EntryParser<R>>,
-    index: Option<IndexParser<R>>,
+    index: Vec<IndexParser<R>>,
+    visited: HashSet<Url>,
+    max_depth: usize,
+    strict: bool,
+    limits: Limits,
 }
 
 impl<R> AutoParser<R> {
@@ -127,12 +278,54 @@ impl<R> AutoParser<R> {
         }
     }
 
-    /// Replaces the currently stored parser.
+    /// Enables or disables the strict mode i.e. skips sitemaps with the root
+    /// element outside of the sitemap namespace. Disabled by default.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Overrides the total records and bytes limits of every fetched
+    /// sitemap. See [`Limits`].
+    pub fn with_limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Sets the maximal nesting level of followed sitemap indexes, deeper
+    /// indexes are skipped. Defaults to 1 i.e. only indexes listed in the
+    /// `robots.txt` file are followed. Cycles are skipped regardless.
+    ///
+    /// ```rust
+    /// use sitemapo::parse::AutoParser;
+    ///
+    /// type SyncReader = std::io::BufReader<std::io::Cursor<Vec<u8>>>;
+    /// let parser = AutoParser::<SyncReader>::new([]).with_max_depth(3);
+    /// ```
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Returns the amount of distinct sitemaps fetched so far.
+    /// Sitemaps referenced more than once are fetched only once.
+    pub fn visited_count(&self) -> usize {
+        self.visited.len()
+    }
+
+    /// Returns `true` if the sitemap was not fetched before, marks it as fetched.
+    fn try_visit(&mut self, sitemap: &Url) -> bool {
+        self.visited.insert(sitemap.clone())
+    }
+
+    /// Replaces the currently stored parser, applying the limits.
     fn replace_parser(&mut self, detector: Scanner<R>) {
-        match detector {
+        match detector.with_limits(self.limits) {
             Scanner::Plain(parser) => self.plain = Some(parser),
             Scanner::Entry(parser) => self.entry = Some(parser),
-            Scanner::Index(parser) => self.index = Some(parser),
+            // Ignore too deeply nested sitemap index.
+            Scanner::Index(parser) if self.index.len() < self.max_depth => self.index.push(parser),
+            Scanner::Index(_) => {}
         }
     }
 
@@ -140,7 +333,7 @@ impl<R> AutoParser<R> {
     pub fn is_empty(&self) -> bool {
         self.sitemaps.is_empty()
             && self.plain.is_none()
-            && self.index.is_none()
+            && self.index.is_empty()
             && self.entry.is_none()
     }
 
@@ -148,17 +341,16 @@ impl<R> AutoParser<R> {
     pub fn len(&self) -> usize {
         self.sitemaps.len()
             + self.plain.is_some() as usize
-            + self.index.is_some() as usize
+            + self.index.len()
             + self.entry.is_some() as usize
     }
 }
 
-// TODO: Iterator.
 impl<R> AutoParser<R>
 where
     R: std::io::BufRead,
 {
-    /// TODO: Desc.
+    /// Reads the next record, resolving sitemap indexes with the fetcher.
     ///
     /// Silently ignores errors, skips failed sitemaps.
     pub fn try_sync<E, A>(&mut self, fetcher: A) -> Result<Option<Entry>, E>
@@ -180,25 +372,36 @@ where
                     return Ok(Some(record));
                 }
 
-                self.plain.take(); // If EOF or Error.
+                self.entry.take(); // If EOF or Error.
             }
 
-            if let Some(parser) = self.index.as_mut() {
+            if let Some(parser) = self.index.last_mut() {
                 if let Ok(Some(record)) = parser.read() {
-                    let reader = (fetcher)(record.location.clone())?;
-                    // Ignore nested sitemap index or error.
-                    match Scanner::from_sync(reader).ok() {
-                        Some(Scanner::Index(_)) | None => {}
-                        Some(parser) => self.replace_parser(parser),
+                    // Skip already fetched sitemaps e.g. self-references.
+                    if !self.try_visit(&record.location) {
+                        continue;
                     }
+
+                    let reader = (fetcher)(record.location)?;
+                    if let Ok(parser) = Scanner::from_sync_limited(reader, self.strict, self.limits)
+                    {
+                        self.replace_parser(parser)
+                    }
+
+                    // Drain the resolved sitemap before the next one.
+                    continue;
                 }
 
-                self.plain.take(); // If EOF or Error.
+                self.index.pop(); // If EOF or Error.
             }
 
             if let Some(sitemap) = self.sitemaps.pop() {
+                if !self.try_visit(&sitemap) {
+                    continue;
+                }
+
                 let reader = (fetcher)(sitemap)?;
-                if let Ok(sitemap) = Scanner::from_sync(reader) {
+                if let Ok(sitemap) = Scanner::from_sync_limited(reader, self.strict, self.limits) {
                     self.replace_parser(sitemap)
                 }
             }
@@ -208,19 +411,94 @@ where
 
         Ok(None)
     }
+
+    /// Converts into the iterator over all records, resolving sitemap
+    /// indexes with the fetcher. See [`AutoParser::try_sync`].
+    ///
+    /// ```rust
+    /// #[derive(Debug, thiserror::Error)]
+    /// enum CustomError {
+    ///     // ..
+    ///     #[error("sitemap error: {0}")]
+    ///     Sitemap(#[from] sitemapo::Error),
+    ///     //..
+    /// }
+    ///
+    /// fn main() -> Result<(), CustomError> {
+    ///     type SyncReader = std::io::BufReader<std::io::Cursor<Vec<u8>>>;
+    ///     fn fetch(_: url::Url) -> Result<SyncReader, CustomError> {
+    ///         // ..
+    ///         unreachable!()
+    ///     }
+    ///
+    ///     // Sitemaps listed in the robots.txt file.
+    ///     let sitemaps = Vec::default();
+    ///
+    ///     let parser = sitemapo::parse::AutoParser::new(sitemaps);
+    ///     let records: Vec<_> = parser.into_iter_with(fetch).collect::<Result<_, _>>()?;
+    ///     assert!(records.is_empty());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn into_iter_with<E, A>(mut self, fetcher: A) -> impl Iterator<Item = Result<Entry, E>>
+    where
+        E: std::error::Error + From<Error>,
+        A: Fn(Url) -> Result<R, E>,
+    {
+        std::iter::from_fn(move || self.try_sync(&fetcher).transpose())
+    }
 }
 
-// TODO: AsyncIterator/Stream.
-// https://tokio.rs/tokio/tutorial/streams
 #[cfg(feature = "tokio")]
 #[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
 impl<R> AutoParser<R>
 where
     R: tokio::io::AsyncBufRead + Unpin + Send,
 {
-    /// TODO: Desc.
+    /// Reads the next record, resolving sitemap indexes with the fetcher.
     ///
     /// Silently ignores errors, skips failed sitemaps.
+    ///
+    /// The fetcher may borrow a shared client (or any other state) as long as
+    /// it outlives the parser, so the client is not cloned for every call:
+    ///
+    /// ```rust
+    /// #[derive(Debug, thiserror::Error)]
+    /// enum CustomError {
+    ///     // ..
+    ///     #[error("sitemap error: {0}")]
+    ///     Sitemap(#[from] sitemapo::Error),
+    ///     //..
+    /// }
+    ///
+    /// type AsyncReader = tokio::io::BufReader<std::io::Cursor<Vec<u8>>>;
+    ///
+    /// struct Client;
+    ///
+    /// impl Client {
+    ///     async fn fetch(&self, _: url::Url) -> Result<AsyncReader, CustomError> {
+    ///         // ..
+    ///         unreachable!()
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main(flavor = "current_thread")]
+    /// async fn main() -> Result<(), CustomError> {
+    ///     let client = Client;
+    ///     let fetch = |url| client.fetch(url);
+    ///
+    ///     // Sitemaps listed in the robots.txt file.
+    ///     let sitemaps = Vec::default();
+    ///
+    ///     let mut parser = sitemapo::parse::AutoParser::new(sitemaps);
+    ///     while let Some(_record) = parser.try_async(&fetch).await? {
+    ///         // ..
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
     pub async fn try_async<E, A, F>(&mut self, fetcher: A) -> Result<Option<Entry>, E>
     where
         E: std::error::Error + From<Error>,
@@ -241,25 +519,37 @@ where
                     return Ok(Some(record));
                 }
 
-                self.plain.take(); // If EOF or Error.
+                self.entry.take(); // If EOF or Error.
             }
 
-            if let Some(parser) = self.index.as_mut() {
+            if let Some(parser) = self.index.last_mut() {
                 if let Ok(Some(record)) = parser.read().await {
-                    let reader = (fetcher)(record.location.clone()).await?;
-                    // Ignore nested sitemap index or error.
-                    match Scanner::from_async(reader).await.ok() {
-                        Some(Scanner::Index(_)) | None => {}
-                        Some(parser) => self.replace_parser(parser),
+                    // Skip already fetched sitemaps e.g. self-references.
+                    if !self.try_visit(&record.location) {
+                        continue;
                     }
+
+                    let reader = (fetcher)(record.location).await?;
+                    let parser = Scanner::from_async_limited(reader, self.strict, self.limits);
+                    if let Ok(parser) = parser.await {
+                        self.replace_parser(parser)
+                    }
+
+                    // Drain the resolved sitemap before the next one.
+                    continue;
                 }
 
-                self.plain.take(); // If EOF or Error.
+                self.index.pop(); // If EOF or Error.
             }
 
             if let Some(sitemap) = self.sitemaps.pop() {
+                if !self.try_visit(&sitemap) {
+                    continue;
+                }
+
                 let reader = (fetcher)(sitemap).await?;
-                if let Ok(parser) = Scanner::from_async(reader).await {
+                let parser = Scanner::from_async_limited(reader, self.strict, self.limits);
+                if let Ok(parser) = parser.await {
                     self.replace_parser(parser)
                 }
             }
@@ -269,6 +559,82 @@ where
 
         Ok(None)
     }
+
+    /// Converts into the stream over all records, resolving sitemap
+    /// indexes with the fetcher. See [`AutoParser::try_async`].
+    ///
+    /// ```rust
+    /// use futures_util::StreamExt;
+    ///
+    /// #[derive(Debug, thiserror::Error)]
+    /// enum CustomError {
+    ///     // ..
+    ///     #[error("sitemap error: {0}")]
+    ///     Sitemap(#[from] sitemapo::Error),
+    ///     //..
+    /// }
+    ///
+    /// #[tokio::main(flavor = "current_thread")]
+    /// async fn main() -> Result<(), CustomError> {
+    ///     type AsyncReader = tokio::io::BufReader<std::io::Cursor<Vec<u8>>>;
+    ///     async fn fetch(_: url::Url) -> Result<AsyncReader, CustomError> {
+    ///         // ..
+    ///         unreachable!()
+    ///     }
+    ///
+    ///     // Sitemaps listed in the robots.txt file.
+    ///     let sitemaps = Vec::default();
+    ///
+    ///     let parser = sitemapo::parse::AutoParser::new(sitemaps);
+    ///     let mut stream = std::pin::pin!(parser.into_stream_with(fetch));
+    ///     while let Some(_record) = stream.next().await {
+    ///         // ..
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn into_stream_with<E, A, F>(
+        self,
+        fetcher: A,
+    ) -> impl futures_util::Stream<Item = Result<Entry, E>>
+    where
+        E: std::error::Error + From<Error>,
+        F: std::future::Future<Output = Result<R, E>>,
+        A: Fn(Url) -> F,
+    {
+        let state = (self, fetcher);
+        futures_util::stream::unfold(state, |(mut this, fetcher)| async move {
+            let next = this.try_async(&fetcher).await.transpose()?;
+            Some((next, (this, fetcher)))
+        })
+    }
+}
+
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+impl<R> AutoParser<tokio::io::BufReader<R>>
+where
+    R: tokio::io::AsyncRead + Unpin + Send,
+{
+    /// Reads the next record, resolving sitemap indexes with the fetcher
+    /// that returns unbuffered readers e.g. HTTP response bodies.
+    ///
+    /// Wraps every fetched reader into the [`tokio::io::BufReader`].
+    /// See [`AutoParser::try_async`].
+    pub async fn try_async_unbuffered<E, A, F>(&mut self, fetcher: A) -> Result<Option<Entry>, E>
+    where
+        E: std::error::Error + From<Error>,
+        F: std::future::Future<Output = Result<R, E>>,
+        A: Fn(Url) -> F,
+    {
+        let fetcher = |url| {
+            let reader = (fetcher)(url);
+            async move { reader.await.map(tokio::io::BufReader::new) }
+        };
+
+        self.try_async(fetcher).await
+    }
 }
 
 impl<R> std::fmt::Debug for AutoParser<R> {
@@ -278,6 +644,7 @@ impl<R> std::fmt::Debug for AutoParser<R> {
             .field("plain", &self.plain)
             .field("index", &self.index)
             .field("entry", &self.entry)
+            .field("visited", &self.visited.len())
             .finish()
     }
 }
@@ -287,8 +654,12 @@ impl<R> Default for AutoParser<R> {
         Self {
             sitemaps: Vec::new(),
             plain: None,
-            index: None,
+            index: Vec::new(),
             entry: None,
+            visited: HashSet::new(),
+            max_depth: 1,
+            strict: false,
+            limits: Limits::default(),
         }
     }
 }
@@ -328,6 +699,290 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn synk_iter() -> Result<(), CustomError> {
+        type SyncReader = std::io::BufReader<std::io::Cursor<Vec<u8>>>;
+        fn sync_fetcher(url: Url) -> Result<SyncReader, CustomError> {
+            let xml = match url.path() {
+                "/index.xml" => INDEX,
+                "/entry.xml" => ENTRY,
+                _ => unreachable!(),
+            };
+
+            let cursor = std::io::Cursor::new(xml.as_bytes().to_vec());
+            Ok(std::io::BufReader::new(cursor))
+        }
+
+        let url = Url::parse("https://example.com/index.xml").unwrap();
+        let parser = AutoParser::new([url]);
+        let records = parser.into_iter_with(sync_fetcher);
+        let records = records.collect::<Result<Vec<_>, _>>()?;
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].location.path(), "/file1.html");
+        assert_eq!(records[1].location.path(), "/file2.html");
+        Ok(())
+    }
+
+    #[test]
+    fn synk_visited() -> Result<(), CustomError> {
+        use std::cell::Cell;
+
+        const LOOP: &str = r#"
+        <sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+            <sitemap><loc>https://example.com/index.xml</loc></sitemap>
+            <sitemap><loc>https://example.com/entry.xml</loc></sitemap>
+            <sitemap><loc>https://example.com/entry.xml</loc></sitemap>
+        </sitemapindex>"#;
+
+        type SyncReader = std::io::BufReader<std::io::Cursor<Vec<u8>>>;
+        let calls = Cell::new(0);
+        let fetcher = |url: Url| -> Result<SyncReader, CustomError> {
+            calls.set(calls.get() + 1);
+            let xml = match url.path() {
+                "/index.xml" => LOOP,
+                "/entry.xml" => ENTRY,
+                _ => unreachable!(),
+            };
+
+            let cursor = std::io::Cursor::new(xml.as_bytes().to_vec());
+            Ok(std::io::BufReader::new(cursor))
+        };
+
+        let url = Url::parse("https://example.com/index.xml").unwrap();
+        let mut parser = AutoParser::new([url.clone(), url]);
+
+        let mut records = Vec::new();
+        while let Some(record) = parser.try_sync(fetcher)? {
+            records.push(record);
+        }
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(calls.get(), 2);
+        assert_eq!(parser.visited_count(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn synk_max_depth() -> Result<(), CustomError> {
+        const NESTED: &str = r#"
+        <sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+            <sitemap><loc>https://example.com/root.xml</loc></sitemap>
+            <sitemap><loc>https://example.com/index.xml</loc></sitemap>
+        </sitemapindex>"#;
+
+        type SyncReader = std::io::BufReader<std::io::Cursor<Vec<u8>>>;
+        fn sync_fetcher(url: Url) -> Result<SyncReader, CustomError> {
+            let xml = match url.path() {
+                "/root.xml" => NESTED,
+                "/index.xml" => INDEX,
+                "/entry.xml" => ENTRY,
+                _ => unreachable!(),
+            };
+
+            let cursor = std::io::Cursor::new(xml.as_bytes().to_vec());
+            Ok(std::io::BufReader::new(cursor))
+        }
+
+        let url = Url::parse("https://example.com/root.xml").unwrap();
+        let parser = AutoParser::new([url.clone()]);
+        let records = parser.into_iter_with(sync_fetcher);
+        assert_eq!(records.count(), 0);
+
+        // Follows root.xml -> index.xml -> entry.xml, skips the cycle.
+        let parser = AutoParser::new([url]).with_max_depth(2);
+        let records = parser.into_iter_with(sync_fetcher);
+        let records = records.collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(records.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn synk_scanner() -> Result<(), CustomError> {
+        let records = Scanner::from_sync(ENTRY.as_bytes())?.read_all()?;
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[1].location.path(), "/file2.html");
+
+        let records = Scanner::from_sync(INDEX.as_bytes())?.read_all()?;
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].location.path(), "/entry.xml");
+        Ok(())
+    }
+
+    #[test]
+    fn synk_missing_root() {
+        for buf in ["", "https://example.com/a\n", "<html></html>"] {
+            let scanner = Scanner::from_sync(buf.as_bytes());
+            assert!(matches!(scanner, Err(Error::MissingRoot)), "{buf}");
+        }
+    }
+
+    #[test]
+    fn synk_limits() -> Result<(), CustomError> {
+        let limits = Limits {
+            records: 1,
+            ..Limits::default()
+        };
+
+        let scanner = Scanner::from_sync(ENTRY.as_bytes())?.with_limits(limits);
+        let result = scanner.read_all();
+        assert!(matches!(result, Err(Error::EntryLimit { .. })));
+
+        let limits = Limits {
+            records: 2,
+            ..Limits::default()
+        };
+
+        type SyncReader = std::io::BufReader<std::io::Cursor<Vec<u8>>>;
+        fn sync_fetcher(_: Url) -> Result<SyncReader, CustomError> {
+            let cursor = std::io::Cursor::new(ENTRY.as_bytes().to_vec());
+            Ok(std::io::BufReader::new(cursor))
+        }
+
+        let url = Url::parse("https://example.com/entry.xml").unwrap();
+        let parser = AutoParser::new([url]).with_limits(limits);
+        let records = parser.into_iter_with(sync_fetcher);
+        assert_eq!(records.count(), 2);
+        Ok(())
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn asynk_scanner() -> Result<(), CustomError> {
+        let scanner = Scanner::from_async(ENTRY.as_bytes()).await?;
+        let records = scanner.read_all_async().await?;
+        assert_eq!(records.len(), 2);
+
+        let scanner = Scanner::from_async("https://example.com/a\n".as_bytes()).await;
+        assert!(matches!(scanner, Err(Error::MissingRoot)));
+        Ok(())
+    }
+
+    const INDEX: &str = r#"
+    <sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+        <sitemap>
+            <loc>https://example.com/entry.xml</loc>
+        </sitemap>
+    </sitemapindex>"#;
+
+    const ENTRY: &str = r#"
+    <urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+        <url><loc>https://example.com/file1.html</loc></url>
+        <url><loc>https://example.com/file2.html</loc></url>
+    </urlset>"#;
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn asynk_stream() -> Result<(), CustomError> {
+        use futures_util::StreamExt;
+
+        type AsyncReader = tokio::io::BufReader<std::io::Cursor<Vec<u8>>>;
+        async fn async_fetcher(url: Url) -> Result<AsyncReader, CustomError> {
+            let xml = match url.path() {
+                "/index.xml" => INDEX,
+                "/entry.xml" => ENTRY,
+                _ => unreachable!(),
+            };
+
+            let cursor = std::io::Cursor::new(xml.as_bytes().to_vec());
+            Ok(tokio::io::BufReader::new(cursor))
+        }
+
+        let url = Url::parse("https://example.com/index.xml").unwrap();
+        let parser = AutoParser::new([url]);
+        let stream = parser.into_stream_with(async_fetcher);
+        let records = stream.collect::<Vec<_>>().await;
+        let records = records.into_iter().collect::<Result<Vec<_>, _>>()?;
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].location.path(), "/file1.html");
+        assert_eq!(records[1].location.path(), "/file2.html");
+        Ok(())
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn asynk_shared() -> Result<(), CustomError> {
+        use std::collections::HashMap;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        type AsyncReader = tokio::io::BufReader<std::io::Cursor<Vec<u8>>>;
+        struct Client {
+            store: HashMap<&'static str, &'static str>,
+            calls: AtomicUsize,
+        }
+
+        impl Client {
+            async fn fetch(&self, url: Url) -> Result<AsyncReader, CustomError> {
+                self.calls.fetch_add(1, Ordering::Relaxed);
+                let xml = self.store.get(url.path()).unwrap();
+                let cursor = std::io::Cursor::new(xml.as_bytes().to_vec());
+                Ok(tokio::io::BufReader::new(cursor))
+            }
+        }
+
+        let client = Client {
+            store: HashMap::from([("/index.xml", INDEX), ("/entry.xml", ENTRY)]),
+            calls: AtomicUsize::new(0),
+        };
+
+        let url = Url::parse("https://example.com/index.xml").unwrap();
+        let mut parser = AutoParser::new([url]);
+        let fetcher = |url| client.fetch(url);
+
+        let mut records = Vec::new();
+        while let Some(record) = parser.try_async(&fetcher).await? {
+            records.push(record);
+        }
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(client.calls.load(Ordering::Relaxed), 2);
+        Ok(())
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn asynk_unbuffered() -> Result<(), CustomError> {
+        use std::pin::Pin;
+        use std::task::{Context, Poll};
+        use tokio::io::{AsyncRead, ReadBuf};
+
+        /// Implements [`AsyncRead`] only, unlike the slice.
+        struct Unbuffered(&'static [u8]);
+
+        impl AsyncRead for Unbuffered {
+            fn poll_read(
+                mut self: Pin<&mut Self>,
+                cx: &mut Context<'_>,
+                buf: &mut ReadBuf<'_>,
+            ) -> Poll<std::io::Result<()>> {
+                Pin::new(&mut self.0).poll_read(cx, buf)
+            }
+        }
+
+        async fn async_fetcher(url: Url) -> Result<Unbuffered, CustomError> {
+            match url.path() {
+                "/index.xml" => Ok(Unbuffered(INDEX.as_bytes())),
+                "/entry.xml" => Ok(Unbuffered(ENTRY.as_bytes())),
+                _ => unreachable!(),
+            }
+        }
+
+        let url = Url::parse("https://example.com/index.xml").unwrap();
+        let mut parser = AutoParser::new([url]);
+        let mut records = Vec::new();
+        while let Some(record) = parser.try_async_unbuffered(async_fetcher).await? {
+            records.push(record);
+        }
+
+        assert_eq!(records.len(), 2);
+
+        let mut parser = EntryParser::new_unbuffered(Unbuffered(ENTRY.as_bytes())).await?;
+        assert!(parser.read().await?.is_some());
+        Ok(())
+    }
+
     #[derive(Debug)]
     struct ResourceState {
         sitemaps: Option<AutoParser<()>>,
@@ -1,8 +1,8 @@
 use quick_xml::events;
-use time::{format_description::well_known::Iso8601, OffsetDateTime};
+use time::OffsetDateTime;
 use url::Url;
 
-use crate::parse::{InnerParser, Output, Parser};
+use crate::parse::{try_parse_date, InnerParser, Limits, Output, Parser};
 use crate::record::*;
 use crate::{Error, Result};
 
@@ -13,6 +13,14 @@ pub(crate) struct EntryFactory {
     modified: Option<OffsetDateTime>,
     priority: Option<Priority>,
     frequency: Option<Frequency>,
+    #[cfg(feature = "extension")]
+    images: Vec<Url>,
+    #[cfg(feature = "extension")]
+    videos: Vec<VideoFactory>,
+    #[cfg(feature = "extension")]
+    alternates: Vec<Alternate>,
+    #[cfg(feature = "extension")]
+    attributes: Vec<(String, String)>,
 }
 
 impl EntryFactory {
@@ -23,6 +31,47 @@ impl EntryFactory {
             rec.modified = self.modified;
             rec.priority = self.priority;
             rec.frequency = self.frequency;
+            #[cfg(feature = "extension")]
+            rec.images.extend(self.images);
+            #[cfg(feature = "extension")]
+            rec.videos
+                .extend(self.videos.into_iter().filter_map(|u| u.build()));
+            #[cfg(feature = "extension")]
+            rec.alternates.extend(self.alternates);
+            #[cfg(feature = "extension")]
+            rec.attributes.extend(self.attributes);
+            rec
+        })
+    }
+
+    /// Applies changes to the last started video, if any.
+    #[cfg(feature = "extension")]
+    fn apply_video(&mut self, apply: impl FnOnce(&mut VideoFactory)) {
+        if let Some(video) = self.videos.last_mut() {
+            apply(video)
+        }
+    }
+}
+
+/// [`VideoEntry`] builder.
+#[cfg(feature = "extension")]
+#[derive(Debug, Clone, Default)]
+pub(crate) struct VideoFactory {
+    thumbnail_location: Option<Url>,
+    title: Option<String>,
+    description: Option<String>,
+    content_location: Option<Url>,
+}
+
+#[cfg(feature = "extension")]
+impl VideoFactory {
+    /// Attempts to construct the new record, requires all mandatory fields.
+    pub fn build(self) -> Option<VideoEntry> {
+        let title = self.title?;
+        let description = self.description?;
+        self.thumbnail_location.map(|u| {
+            let mut rec = VideoEntry::new(u, &title, &description);
+            rec.content_location = self.content_location;
             rec
         })
     }
@@ -82,6 +131,35 @@ impl<R> EntryParser<R> {
         Self { inner }
     }
 
+    /// Enables or disables the strict mode i.e. expects the root element
+    /// in the sitemap namespace, see [`Error::Namespace`]. Disabled by default.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.inner.strict = strict;
+        self
+    }
+
+    /// Overrides the total records and bytes limits. See [`Limits`].
+    pub fn with_limits(mut self, limits: Limits) -> Self {
+        self.inner.limits = limits;
+        self
+    }
+
+    /// Returns the total amount of skipped invalid or malformed records.
+    /// See [`EntryParser::read_lenient`].
+    pub fn skipped(&self) -> usize {
+        self.inner.skipped
+    }
+
+    /// Returns the total amount of records read so far, including skipped ones.
+    pub fn records_read(&self) -> usize {
+        self.inner.records
+    }
+
+    /// Returns the total amount of bytes read from the underlying reader.
+    pub fn bytes_read(&self) -> usize {
+        self.inner.reader.get_ref().reader_bytes()
+    }
+
     /// Returns a reference to the underlying reader.
     pub fn get_ref(&self) -> &R {
         self.inner.get_ref()
@@ -102,29 +180,122 @@ impl<R> EntryParser<R> {
         static MOD: [&str; 3] = [URL_SET, URL, LAST_MODIFIED];
         static FRQ: [&str; 3] = [URL_SET, URL, CHANGE_FREQUENCY];
         static PRI: [&str; 3] = [URL_SET, URL, PRIORITY];
+        #[cfg(feature = "extension")]
+        static IMG: [&str; 4] = [URL_SET, URL, IMAGE, IMAGE_LOCATION];
+        #[cfg(feature = "extension")]
+        static VTH: [&str; 4] = [URL_SET, URL, VIDEO, VIDEO_THUMBNAIL_LOCATION];
+        #[cfg(feature = "extension")]
+        static VTI: [&str; 4] = [URL_SET, URL, VIDEO, VIDEO_TITLE];
+        #[cfg(feature = "extension")]
+        static VDE: [&str; 4] = [URL_SET, URL, VIDEO, VIDEO_DESCRIPTION];
+        #[cfg(feature = "extension")]
+        static VCO: [&str; 4] = [URL_SET, URL, VIDEO, VIDEO_CONTENT_LOCATION];
 
         if let Some(rec) = &mut inner.record {
             match inner.path.as_slice() {
                 x if x == LOC => rec.location = Url::parse(text).ok(),
-                x if x == MOD => rec.modified = OffsetDateTime::parse(text, &Iso8601::PARSING).ok(),
+                x if x == MOD => rec.modified = try_parse_date(text),
                 x if x == FRQ => rec.frequency = Frequency::parse(text).ok(),
                 x if x == PRI => rec.priority = Priority::parse(text).ok(),
+                #[cfg(feature = "extension")]
+                x if x == IMG => rec.images.extend(Url::parse(text).ok()),
+                #[cfg(feature = "extension")]
+                x if x == VTH => rec.apply_video(|v| v.thumbnail_location = Url::parse(text).ok()),
+                #[cfg(feature = "extension")]
+                x if x == VTI => rec.apply_video(|v| v.title = Some(text.to_string())),
+                #[cfg(feature = "extension")]
+                x if x == VDE => rec.apply_video(|v| v.description = Some(text.to_string())),
+                #[cfg(feature = "extension")]
+                x if x == VCO => rec.apply_video(|v| v.content_location = Url::parse(text).ok()),
                 _ => {}
             }
         }
     }
 
+    /// Applies the start (or self-closing) tag of the nested element
+    /// to the current record.
+    #[cfg(feature = "extension")]
+    fn apply_start(&mut self, event: &events::Event) {
+        let (bytes, rec) = match (event, &mut self.inner.record) {
+            (events::Event::Start(bytes), Some(rec)) => (bytes, rec),
+            (events::Event::Empty(bytes), Some(rec)) => (bytes, rec),
+            _ => return,
+        };
+
+        match bytes.name().into_inner() {
+            x if x == VIDEO.as_bytes() => rec.videos.push(VideoFactory::default()),
+            x if x == XHTML_LINK.as_bytes() => rec.alternates.extend(Self::parse_alternate(bytes)),
+            _ => {}
+        }
+    }
+
+    /// Attempts to construct the new alternate from the `xhtml:link` attributes.
+    #[cfg(feature = "extension")]
+    fn parse_alternate(bytes: &events::BytesStart) -> Option<Alternate> {
+        let (mut rel, mut hreflang, mut href) = (None, None, None);
+        for attr in bytes.attributes().flatten() {
+            let value = attr.unescape_value().ok();
+            match attr.key.into_inner() {
+                b"rel" => rel = value,
+                b"hreflang" => hreflang = value,
+                b"href" => href = value.and_then(|u| Url::parse(&u).ok()),
+                _ => {}
+            }
+        }
+
+        if rel.as_deref() != Some("alternate") {
+            return None;
+        }
+
+        Some(Alternate::new(&hreflang?, href?))
+    }
+
+    /// Collects attributes of the `url` start tag, if it is one.
+    #[cfg(feature = "extension")]
+    fn parse_attributes(event: &events::Event) -> Option<Vec<(String, String)>> {
+        let events::Event::Start(bytes) = event else {
+            return None;
+        };
+
+        if bytes.name().into_inner() != URL.as_bytes() {
+            return None;
+        }
+
+        let attributes = bytes.attributes().flatten().filter_map(|attr| {
+            let key = String::from_utf8(attr.key.into_inner().to_vec()).ok()?;
+            let value = attr.unescape_value().ok()?.into_owned();
+            Some((key, value))
+        });
+
+        Some(attributes.collect())
+    }
+
     pub(crate) fn write_event(&mut self, event: events::Event) -> Result<Output<Entry>> {
+        #[cfg(feature = "extension")]
+        self.apply_start(&event);
+        #[cfg(feature = "extension")]
+        let attributes = Self::parse_attributes(&event);
+
         let tag = URL.as_bytes();
-        let builder = self.inner.write_event(event, tag, Self::apply_inner);
+        let builder = self.inner.write_event(event, tag, Self::apply_inner)?;
 
-        if let Ok(Output::Some(r)) = builder {
-            if let Some(record) = r.build() {
-                return Ok(Output::Some(record));
-            }
+        // Applies attributes to the record started by the event.
+        #[cfg(feature = "extension")]
+        if let (Some(attributes), Some(rec)) = (attributes, &mut self.inner.record) {
+            rec.attributes = attributes;
         }
 
-        Ok(Output::None)
+        match builder {
+            Output::Some(r) => match r.build() {
+                Some(record) => Ok(Output::Some(record)),
+                None => {
+                    self.inner.skipped += 1;
+                    Ok(Output::None)
+                }
+            },
+            Output::None => Ok(Output::None),
+            Output::End => Ok(Output::End),
+        }
     }
 }
 
@@ -136,6 +307,34 @@ impl<R> std::fmt::Debug for EntryParser<R> {
     }
 }
 
+impl<R: std::io::BufRead> EntryParser<R> {
+    /// Reads another valid record from the underlying reader, skipping
+    /// malformed records (e.g. with unbalanced tags) instead of failing.
+    ///
+    /// Switches the parser into the lenient mode, the reader no longer checks
+    /// that closing tags match. Limit and IO errors are still returned.
+    /// Recovery is lenient-only, [`Parser::read`] fails on malformed records
+    /// with [`Error::Xml`]. See [`EntryParser::skipped`].
+    pub fn read_lenient(&mut self) -> Result<Option<Entry>> {
+        self.inner.set_lenient();
+        let mut buf = Vec::new();
+        loop {
+            self.inner.try_if_readable()?;
+            let position = self.inner.reader.buffer_position();
+            let output = match self.inner.reader.read_event_into(&mut buf) {
+                Ok(event) => self.write_event(event),
+                Err(err) => Err(err.into()),
+            };
+
+            match self.inner.try_recover(output, position)? {
+                Output::Some(record) => return Ok(Some(record)),
+                Output::None => {}
+                Output::End => return Ok(None),
+            }
+        }
+    }
+}
+
 impl<R: std::io::BufRead> Parser<R, Entry> for EntryParser<R> {
     type Error = Error;
 
@@ -165,12 +364,43 @@ impl<R: std::io::BufRead> Parser<R, Entry> for EntryParser<R> {
 #[cfg(feature = "tokio")]
 #[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
 mod async_parser {
-    use tokio::io::AsyncBufRead;
+    use tokio::io::{AsyncBufRead, AsyncRead, BufReader};
 
     use crate::parse::{AsyncParser, EntryParser, Output};
     use crate::record::Entry;
     use crate::{Error, Result};
 
+    impl<R: AsyncRead + Unpin + Send> EntryParser<BufReader<R>> {
+        /// Creates a new instance with the given unbuffered reader,
+        /// wrapping it into the [`BufReader`].
+        pub async fn new_unbuffered(reader: R) -> Result<Self> {
+            Ok(Self::from_reader(BufReader::new(reader)))
+        }
+    }
+
+    impl<R: AsyncBufRead + Unpin + Send> EntryParser<R> {
+        /// Reads another valid record from the underlying reader, skipping
+        /// malformed records instead of failing. See [`EntryParser::read_lenient`].
+        pub async fn read_lenient_async(&mut self) -> Result<Option<Entry>> {
+            self.inner.set_lenient();
+            let mut buf = Vec::new();
+            loop {
+                self.inner.try_if_readable()?;
+                let position = self.inner.reader.buffer_position();
+                let output = match self.inner.reader.read_event_into_async(&mut buf).await {
+                    Ok(event) => self.write_event(event),
+                    Err(err) => Err(err.into()),
+                };
+
+                match self.inner.try_recover(output, position)? {
+                    Output::Some(record) => return Ok(Some(record)),
+                    Output::None => {}
+                    Output::End => return Ok(None),
+                }
+            }
+        }
+    }
+
     #[async_trait::async_trait]
     impl<R: AsyncBufRead + Unpin + Send> AsyncParser<R, Entry> for EntryParser<R> {
         type Error = Error;
@@ -207,7 +437,7 @@ mod test {
     use crate::record::Entry;
     use crate::Result;
 
-    const EXAMPLE: &'static str = r#"
+    const EXAMPLE: &str = r#"
     <urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
         <url>
             <loc>https://www.example.com/file1.html</loc>
@@ -232,6 +462,247 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn synk_counters() -> Result<()> {
+        use crate::parse::Parser;
+
+        let mut parser = EntryParser::new(EXAMPLE.as_bytes())?;
+        assert_eq!((parser.records_read(), parser.bytes_read()), (0, 0));
+        while parser.read()?.is_some() {}
+        assert_eq!(parser.records_read(), 1);
+        assert_eq!(parser.bytes_read(), EXAMPLE.len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn synk_limits() -> Result<()> {
+        use crate::parse::{Limits, Parser};
+        use crate::Error;
+
+        let limits = Limits {
+            records: 2,
+            ..Limits::default()
+        };
+
+        let buf = r#"
+        <urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+            <url><loc>https://www.example.com/file1.html</loc></url>
+            <url><loc>https://www.example.com/file2.html</loc></url>
+            <url><loc>https://www.example.com/file3.html</loc></url>
+        </urlset>"#;
+
+        let mut parser = EntryParser::new(buf.as_bytes())?.with_limits(limits);
+        assert!(parser.read()?.is_some());
+        assert!(parser.read()?.is_some());
+        assert!(matches!(parser.read(), Err(Error::EntryLimit { .. })));
+
+        let limits = Limits {
+            bytes: 16,
+            ..Limits::default()
+        };
+
+        let mut parser = EntryParser::new(EXAMPLE.as_bytes())?.with_limits(limits);
+        assert!(matches!(parser.read(), Err(Error::ByteLimit { .. })));
+
+        Ok(())
+    }
+
+    #[test]
+    fn synk_eof() -> Result<()> {
+        use crate::parse::Parser;
+
+        let mut parser = EntryParser::new(EXAMPLE.as_bytes())?;
+        assert!(parser.read()?.is_some());
+        assert!(parser.read()?.is_none());
+        assert!(parser.read()?.is_none());
+
+        Ok(())
+    }
+
+    const MALFORMED: &str = r#"
+    <urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+        <url><loc>https://www.example.com/file1.html</loc></url>
+        <url><loc>https://www.example.com/file2.html</lo></url>
+        <url><loc>not a url</loc></url>
+        <url><loc>https://www.example.com/file3.html</loc></url>
+    </urlset>"#;
+
+    #[test]
+    fn synk_lenient() -> Result<()> {
+        use crate::parse::Parser;
+
+        let mut parser = EntryParser::new(MALFORMED.as_bytes())?;
+        let mut records = Vec::new();
+        while let Some(record) = parser.read_lenient()? {
+            records.push(record.location.path().to_string());
+        }
+
+        assert_eq!(records, ["/file1.html", "/file3.html"]);
+        assert_eq!(parser.skipped(), 2);
+
+        let mut parser = EntryParser::new(MALFORMED.as_bytes())?;
+        assert!(parser.read()?.is_some());
+        assert!(parser.read().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn synk_missing_close() -> Result<()> {
+        use crate::parse::Parser;
+        use crate::Error;
+
+        let buf = r#"
+        <urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+            <url><loc>https://www.example.com/file1.html</url>
+            <url>
+                <loc>https://www.example.com/file2.html</loc>
+                <lastmod>2022-06-04</lastmod>
+            </url>
+        </urlset>"#;
+
+        let mut parser = EntryParser::new(buf.as_bytes())?;
+        let record = parser.read_lenient()?.unwrap();
+        assert_eq!(record.location.path(), "/file2.html");
+        assert!(record.modified.is_some());
+        assert!(parser.read_lenient()?.is_none());
+        assert_eq!(parser.skipped(), 1);
+
+        let mut parser = EntryParser::new(buf.as_bytes())?;
+        assert!(matches!(parser.read(), Err(Error::Xml(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn synk_strict() -> Result<()> {
+        use crate::parse::Parser;
+        use crate::Error;
+
+        let buf = r#"
+        <urlset xmlns="http://www.example.com/schemas/sitemap/0.9">
+            <url><loc>https://www.example.com/file1.html</loc></url>
+        </urlset>"#;
+
+        let mut parser = EntryParser::new(buf.as_bytes())?;
+        assert!(parser.read()?.is_some());
+
+        let mut parser = EntryParser::new(buf.as_bytes())?.with_strict(true);
+        let found = "http://www.example.com/schemas/sitemap/0.9".to_string();
+        assert!(matches!(parser.read(), Err(Error::Namespace { found: u }) if u == found));
+
+        let mut parser = EntryParser::new(EXAMPLE.as_bytes())?.with_strict(true);
+        assert!(parser.read()?.is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn synk_cdata() -> Result<()> {
+        use crate::parse::Parser;
+
+        let buf = r#"
+        <urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+            <url><loc><![CDATA[https://www.example.com/file1.html?a=1&b=2]]></loc></url>
+        </urlset>"#;
+
+        let mut parser = EntryParser::new(buf.as_bytes())?;
+        let record: Entry = parser.read()?.unwrap();
+
+        let exp = Url::parse("https://www.example.com/file1.html?a=1&b=2");
+        assert_eq!(record.location, exp.unwrap());
+
+        Ok(())
+    }
+
+    #[test]
+    fn synk_lastmod() -> Result<()> {
+        use crate::parse::Parser;
+        use time::macros::datetime;
+
+        let cases = [
+            ("2022-06-04", datetime!(2022-06-04 0:00 UTC)),
+            ("2022-06-04T10:00:00Z", datetime!(2022-06-04 10:00 UTC)),
+            ("2022-06-04T10:00:00-04:00", datetime!(2022-06-04 10:00 -4)),
+            ("2022-06", datetime!(2022-06-01 0:00 UTC)),
+        ];
+
+        for (lastmod, exp) in cases {
+            let buf = format!(
+                "<urlset><url><loc>https://example.com/</loc><lastmod>{lastmod}</lastmod></url></urlset>"
+            );
+
+            let mut parser = EntryParser::new(buf.as_bytes())?;
+            let record: Entry = parser.read()?.unwrap();
+            assert_eq!(record.modified, Some(exp), "{lastmod}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn synk_lastmod_timestamp() -> Result<()> {
+        use crate::parse::Parser;
+        use time::macros::datetime;
+
+        let buf = "<urlset><url><loc>https://example.com/</loc><lastmod>1664582400</lastmod></url></urlset>";
+        let mut parser = EntryParser::new(buf.as_bytes())?;
+        let record: Entry = parser.read()?.unwrap();
+
+        let exp = datetime!(2022-10-01 0:00 UTC);
+        let exp = cfg!(feature = "lenient").then_some(exp);
+        assert_eq!(record.modified, exp);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "extension")]
+    #[test]
+    fn synk_partial_video() -> Result<()> {
+        use crate::parse::Parser;
+
+        let buf = r#"
+        <urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+            <url>
+                <loc>https://www.example.com/file1.html</loc>
+                <video:video>
+                    <video:title>Missing thumbnail</video:title>
+                    <video:description>Description</video:description>
+                </video:video>
+                <video:video>
+                    <video:thumbnail_loc>https://www.example.com/1.jpg</video:thumbnail_loc>
+                    <video:title>Title</video:title>
+                    <video:description>Description</video:description>
+                </video:video>
+            </url>
+        </urlset>"#;
+
+        let mut parser = EntryParser::new(buf.as_bytes())?;
+        let record: Entry = parser.read()?.unwrap();
+        assert_eq!(record.videos.len(), 1);
+        assert_eq!(record.videos[0].title, "Title");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn asynk_lenient() -> Result<()> {
+        use crate::parse::AsyncParser;
+
+        let mut parser = EntryParser::new(MALFORMED.as_bytes()).await?;
+        let mut records = Vec::new();
+        while let Some(record) = parser.read_lenient_async().await? {
+            records.push(record.location.path().to_string());
+        }
+
+        assert_eq!(records, ["/file1.html", "/file3.html"]);
+        assert_eq!(parser.skipped(), 2);
+
+        Ok(())
+    }
+
     #[cfg(feature = "tokio")]
     #[tokio::test]
     async fn asynk() -> Result<()> {
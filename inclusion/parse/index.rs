@@ -1,8 +1,8 @@
 use quick_xml::events;
-use time::{format_description::well_known::Iso8601, OffsetDateTime};
+use time::OffsetDateTime;
 use url::Url;
 
-use crate::parse::{InnerParser, Output, Parser};
+use crate::parse::{try_parse_date, InnerParser, Limits, Output, Parser};
 use crate::record::*;
 use crate::{Error, Result};
 
@@ -57,6 +57,35 @@ impl<R> IndexParser<R> {
         Self { inner }
     }
 
+    /// Enables or disables the strict mode i.e. expects the root element
+    /// in the sitemap namespace, see [`Error::Namespace`]. Disabled by default.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.inner.strict = strict;
+        self
+    }
+
+    /// Overrides the total records and bytes limits. See [`Limits`].
+    pub fn with_limits(mut self, limits: Limits) -> Self {
+        self.inner.limits = limits;
+        self
+    }
+
+    /// Returns the total amount of skipped invalid or malformed records.
+    /// See [`IndexParser::read_lenient`].
+    pub fn skipped(&self) -> usize {
+        self.inner.skipped
+    }
+
+    /// Returns the total amount of records read so far, including skipped ones.
+    pub fn records_read(&self) -> usize {
+        self.inner.records
+    }
+
+    /// Returns the total amount of bytes read from the underlying reader.
+    pub fn bytes_read(&self) -> usize {
+        self.inner.reader.get_ref().reader_bytes()
+    }
+
     /// Returns a reference to the underlying reader.
     pub fn get_ref(&self) -> &R {
         self.inner.reader.get_ref().get_ref()
@@ -79,7 +108,7 @@ impl<R> IndexParser<R> {
         if let Some(rec) = &mut inner.record {
             match inner.path.as_slice() {
                 x if x == LOC => rec.location = Url::parse(text).ok(),
-                x if x == MOD => rec.modified = OffsetDateTime::parse(text, &Iso8601::PARSING).ok(),
+                x if x == MOD => rec.modified = try_parse_date(text),
                 _ => {}
             }
         }
@@ -87,15 +116,19 @@ impl<R> IndexParser<R> {
 
     pub(crate) fn write_event(&mut self, event: events::Event) -> Result<Output<Index>> {
         let tag = SITEMAP.as_bytes();
-        let builder = self.inner.write_event(event, tag, Self::apply_inner);
+        let builder = self.inner.write_event(event, tag, Self::apply_inner)?;
 
-        if let Ok(Output::Some(r)) = builder {
-            if let Some(record) = r.build() {
-                return Ok(Output::Some(record));
-            }
+        match builder {
+            Output::Some(r) => match r.build() {
+                Some(record) => Ok(Output::Some(record)),
+                None => {
+                    self.inner.skipped += 1;
+                    Ok(Output::None)
+                }
+            },
+            Output::None => Ok(Output::None),
+            Output::End => Ok(Output::End),
         }
-
-        Ok(Output::None)
     }
 }
 
@@ -107,6 +140,64 @@ impl<R> std::fmt::Debug for IndexParser<R> {
     }
 }
 
+impl<R: std::io::BufRead> IndexParser<R> {
+    /// Reads another valid record from the underlying reader, skipping
+    /// malformed records (e.g. with unbalanced tags) instead of failing.
+    ///
+    /// Switches the parser into the lenient mode, the reader no longer checks
+    /// that closing tags match. Limit and IO errors are still returned.
+    /// Recovery is lenient-only, [`Parser::read`] fails on malformed records
+    /// with [`Error::Xml`]. See [`IndexParser::skipped`].
+    pub fn read_lenient(&mut self) -> Result<Option<Index>> {
+        self.inner.set_lenient();
+        let mut buf = Vec::new();
+        loop {
+            self.inner.try_if_readable()?;
+            let position = self.inner.reader.buffer_position();
+            let output = match self.inner.reader.read_event_into(&mut buf) {
+                Ok(event) => self.write_event(event),
+                Err(err) => Err(err.into()),
+            };
+
+            match self.inner.try_recover(output, position)? {
+                Output::Some(record) => return Ok(Some(record)),
+                Output::None => {}
+                Output::End => return Ok(None),
+            }
+        }
+    }
+}
+
+impl<R: std::io::BufRead> IndexParser<R> {
+    /// Reads all remaining records and returns their locations i.e. child
+    /// sitemaps, without fetching them. See [`AutoParser`] to resolve them.
+    ///
+    /// ```rust
+    /// use sitemapo::parse::{IndexParser, Parser};
+    ///
+    /// fn main() -> sitemapo::Result<()> {
+    ///     let buf = r#"<sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+    ///         <sitemap><loc>https://example.com/sitemap1.xml</loc></sitemap>
+    ///         <sitemap><loc>https://example.com/sitemap2.xml</loc></sitemap>
+    ///     </sitemapindex>"#.as_bytes();
+    ///
+    ///     let locations = IndexParser::new(buf)?.locations()?;
+    ///     assert_eq!(locations.len(), 2);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`AutoParser`]: crate::parse::AutoParser
+    pub fn locations(mut self) -> Result<Vec<Url>> {
+        let mut locations = Vec::new();
+        while let Some(record) = self.read()? {
+            locations.push(record.location);
+        }
+
+        Ok(locations)
+    }
+}
+
 impl<R: std::io::BufRead> Parser<R, Index> for IndexParser<R> {
     type Error = Error;
 
@@ -136,12 +227,57 @@ impl<R: std::io::BufRead> Parser<R, Index> for IndexParser<R> {
 #[cfg(feature = "tokio")]
 #[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
 mod tokio {
-    use tokio::io::AsyncBufRead;
+    use tokio::io::{AsyncBufRead, AsyncRead, BufReader};
+    use url::Url;
 
     use crate::parse::{AsyncParser, IndexParser, Output};
     use crate::record::*;
     use crate::{Error, Result};
 
+    impl<R: AsyncRead + Unpin + Send> IndexParser<BufReader<R>> {
+        /// Creates a new instance with the given unbuffered reader,
+        /// wrapping it into the [`BufReader`].
+        pub async fn new_unbuffered(reader: R) -> Result<Self> {
+            Ok(Self::from_reader(BufReader::new(reader)))
+        }
+    }
+
+    impl<R: AsyncBufRead + Unpin + Send> IndexParser<R> {
+        /// Reads another valid record from the underlying reader, skipping
+        /// malformed records instead of failing. See [`IndexParser::read_lenient`].
+        pub async fn read_lenient_async(&mut self) -> Result<Option<Index>> {
+            self.inner.set_lenient();
+            let mut buf = Vec::new();
+            loop {
+                self.inner.try_if_readable()?;
+                let position = self.inner.reader.buffer_position();
+                let output = match self.inner.reader.read_event_into_async(&mut buf).await {
+                    Ok(event) => self.write_event(event),
+                    Err(err) => Err(err.into()),
+                };
+
+                match self.inner.try_recover(output, position)? {
+                    Output::Some(record) => return Ok(Some(record)),
+                    Output::None => {}
+                    Output::End => return Ok(None),
+                }
+            }
+        }
+    }
+
+    impl<R: AsyncBufRead + Unpin + Send> IndexParser<R> {
+        /// Reads all remaining records and returns their locations.
+        /// See [`IndexParser::locations`].
+        pub async fn locations_async(mut self) -> Result<Vec<Url>> {
+            let mut locations = Vec::new();
+            while let Some(record) = self.read().await? {
+                locations.push(record.location);
+            }
+
+            Ok(locations)
+        }
+    }
+
     #[async_trait::async_trait]
     impl<R: AsyncBufRead + Unpin + Send> AsyncParser<R, Index> for IndexParser<R> {
         type Error = Error;
@@ -169,3 +305,71 @@ mod tokio {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::parse::IndexParser;
+    use crate::Result;
+
+    const EXAMPLE: &str = r#"
+    <sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+        <sitemap>
+            <loc>https://example.com/sitemap1.xml</loc>
+            <lastmod>2004-10-01T18:23:17+00:00</lastmod>
+        </sitemap>
+        <sitemap>
+            <loc>https://example.com/sitemap2.xml</loc>
+        </sitemap>
+    </sitemapindex>"#;
+
+    #[test]
+    fn synk_locations() -> Result<()> {
+        use crate::parse::Parser;
+
+        let parser = IndexParser::new(EXAMPLE.as_bytes())?;
+        let locations = parser.locations()?;
+        let paths: Vec<_> = locations.iter().map(|u| u.path()).collect();
+        assert_eq!(paths, ["/sitemap1.xml", "/sitemap2.xml"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn synk_limits() -> Result<()> {
+        use crate::parse::{Limits, Parser};
+        use crate::Error;
+
+        let limits = Limits {
+            records: 2,
+            ..Limits::default()
+        };
+
+        let mut parser = IndexParser::new(EXAMPLE.as_bytes())?.with_limits(limits);
+        assert!(parser.read()?.is_some());
+        assert!(parser.read()?.is_some());
+        assert!(parser.read()?.is_none());
+
+        let limits = Limits {
+            records: 1,
+            ..Limits::default()
+        };
+
+        let mut parser = IndexParser::new(EXAMPLE.as_bytes())?.with_limits(limits);
+        assert!(parser.read()?.is_some());
+        assert!(matches!(parser.read(), Err(Error::EntryLimit { .. })));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn asynk_locations() -> Result<()> {
+        use crate::parse::AsyncParser;
+
+        let parser = IndexParser::new(EXAMPLE.as_bytes()).await?;
+        let locations = parser.locations_async().await?;
+        assert_eq!(locations.len(), 2);
+
+        Ok(())
+    }
+}
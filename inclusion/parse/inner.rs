@@ -2,8 +2,8 @@ use bytes::Bytes;
 use countio::Counter;
 use quick_xml::{events::Event, Reader};
 
-use crate::parse::try_if_readable;
-use crate::Result;
+use crate::parse::{try_if_namespace, Limits};
+use crate::{Error, Result};
 
 pub(crate) enum Output<T> {
     /// Next record.
@@ -24,17 +24,28 @@ pub(crate) struct InnerParser<R, D> {
     pub(crate) record: Option<D>,
     pub(crate) reader: Reader<Counter<R>>,
     pub(crate) records: usize,
+    pub(crate) skipped: usize,
     pub(crate) path: Vec<Bytes>,
+    pub(crate) strict: bool,
+    pub(crate) limits: Limits,
 }
 
 impl<R, D> InnerParser<R, D> {
     /// Creates a new instance with the given reader.
     pub fn from_reader(reader: R) -> Self {
+        Self::from_xml_reader(Reader::from_reader(Counter::new(reader)))
+    }
+
+    /// Creates a new instance with the given (possibly advanced) XML reader.
+    pub fn from_xml_reader(reader: Reader<Counter<R>>) -> Self {
         Self {
             record: None,
-            reader: Reader::from_reader(Counter::new(reader)),
+            reader,
             records: 0,
+            skipped: 0,
             path: Vec::default(),
+            strict: false,
+            limits: Limits::default(),
         }
     }
 
@@ -54,7 +65,37 @@ impl<R, D> InnerParser<R, D> {
     }
 
     pub fn try_if_readable(&mut self) -> Result<()> {
-        try_if_readable(self.records, self.reader.get_ref().reader_bytes())
+        self.limits
+            .try_if_readable(self.records, self.reader.get_ref().reader_bytes())
+    }
+
+    /// Drops the current (malformed) record if any.
+    pub fn skip_record(&mut self) {
+        if self.record.take().is_some() {
+            self.skipped += 1;
+        }
+    }
+
+    /// Switches into the lenient mode i.e. unbalanced closing tags only
+    /// drop the current record instead of failing the reader.
+    pub fn set_lenient(&mut self) {
+        self.reader.check_end_names(false);
+    }
+
+    /// Recovers from the malformed XML by skipping the current record,
+    /// unless the reader has not advanced since the given position.
+    pub fn try_recover<T>(
+        &mut self,
+        output: Result<Output<T>>,
+        position: usize,
+    ) -> Result<Output<T>> {
+        match output {
+            Err(Error::Xml(_)) if self.reader.buffer_position() > position => {
+                self.skip_record();
+                Ok(Output::None)
+            }
+            output => output,
+        }
     }
 
     /// TODO: Desc.
@@ -66,9 +107,14 @@ impl<R, D> InnerParser<R, D> {
         match next {
             // Replace the old record builder with the new one.
             Event::Start(bytes) => {
+                if self.strict && self.path.is_empty() {
+                    try_if_namespace(&bytes)?;
+                }
+
                 let name = bytes.name().into_inner();
                 if name.eq_ignore_ascii_case(tag) {
                     self.records += 1;
+                    self.try_if_readable()?;
                     let instance = D::default();
                     self.record.replace(instance);
                 }
@@ -82,16 +128,32 @@ impl<R, D> InnerParser<R, D> {
                 apply(self, &text);
             }
 
+            // CDATA sections are not escaped, only decoded.
+            Event::CData(bytes) => {
+                let text = self.reader.decoder().decode(&bytes)?;
+                apply(self, &text);
+            }
+
             // Return the current record if the closing tag is matched.
             Event::End(bytes) => {
-                let name = bytes.name().into_inner().to_vec();
-                if self.path.pop() != Some(name.clone().into()) {
-                    // TODO: Skip til next start tag.
+                let name = bytes.name().into_inner();
+                match self.path.iter().rposition(|u| u.as_ref() == name) {
+                    Some(pos) if pos + 1 == self.path.len() => {
+                        self.path.pop();
+                    }
+                    // Unbalanced closing tag, unwinds to the matching start tag.
+                    Some(pos) => {
+                        self.path.truncate(pos);
+                        self.skip_record();
+                    }
+                    // Unexpected closing tag, the path stays untouched.
+                    None => self.skip_record(),
                 }
 
+                // Stray closing tag (e.g. of the skipped record) doesn't end the parsing.
                 if name.eq_ignore_ascii_case(tag) {
                     let rec = self.record.take();
-                    return Ok(rec.into());
+                    return Ok(rec.map(Output::Some).unwrap_or(Output::None));
                 }
             }
 
@@ -113,6 +175,9 @@ impl<R, D> std::fmt::Debug for InnerParser<R, D> {
         f.debug_struct("InnerParser")
             .field("bytes", &self.reader.get_ref().reader_bytes())
             .field("records", &self.records)
+            .field("skipped", &self.skipped)
+            .field("strict", &self.strict)
+            .field("limits", &self.limits)
             .finish()
     }
 }
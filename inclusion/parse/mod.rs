@@ -41,17 +41,113 @@ pub trait AsyncParser<R: tokio::io::AsyncRead, D>: Sized {
     async fn close(self) -> Result<R, Self::Error>;
 }
 
-pub(crate) fn try_if_readable(records: usize, bytes: usize) -> crate::Result<()> {
-    use crate::record::{BYTE_LIMIT, RECORD_LIMIT};
+/// Total records and bytes limits enforced by parsers.
+///
+/// Defaults to [`RECORD_LIMIT`] and [`BYTE_LIMIT`], could be raised e.g.
+/// for non-public sitemaps that legitimately exceed them.
+///
+/// ```rust
+/// use sitemapo::parse::{EntryParser, Limits, Parser};
+///
+/// let buf = "<urlset></urlset>".as_bytes();
+/// let limits = Limits { records: 100_000, ..Limits::default() };
+/// let parser = EntryParser::new(buf).unwrap().with_limits(limits);
+/// ```
+///
+/// [`RECORD_LIMIT`]: crate::record::RECORD_LIMIT
+/// [`BYTE_LIMIT`]: crate::record::BYTE_LIMIT
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    /// Total records limit, inclusive.
+    pub records: usize,
+    /// Total bytes limit.
+    pub bytes: usize,
+}
+
+impl Limits {
+    /// Returns an error if the records read so far exceed the limits.
+    pub(crate) fn try_if_readable(&self, records: usize, bytes: usize) -> crate::Result<()> {
+        if records > self.records {
+            let over = records - self.records;
+            return Err(crate::Error::EntryLimit { over });
+        }
 
-    if records + 1 > RECORD_LIMIT {
-        return Err(crate::Error::EntryLimit { over: 1 });
+        if bytes > self.bytes {
+            let over = bytes - self.bytes;
+            return Err(crate::Error::ByteLimit { over });
+        }
+
+        Ok(())
     }
+}
 
-    if bytes > BYTE_LIMIT {
-        let over = bytes - BYTE_LIMIT;
-        return Err(crate::Error::ByteLimit { over });
+impl Default for Limits {
+    fn default() -> Self {
+        use crate::record::{BYTE_LIMIT, RECORD_LIMIT};
+
+        Self {
+            records: RECORD_LIMIT,
+            bytes: BYTE_LIMIT,
+        }
+    }
+}
+
+/// Returns an error unless the root element declares the sitemap namespace.
+pub(crate) fn try_if_namespace(bytes: &quick_xml::events::BytesStart) -> crate::Result<()> {
+    use crate::record::XMLNS;
+
+    let (key, expected) = XMLNS;
+    let found = bytes
+        .try_get_attribute(key)?
+        .map(|attr| attr.unescape_value().map(|u| u.into_owned()))
+        .transpose()?
+        .unwrap_or_default();
+
+    if found != expected {
+        return Err(crate::Error::Namespace { found });
     }
 
     Ok(())
 }
+
+/// Parses the W3C Datetime value of the `lastmod` tag.
+///
+/// Falls back to midnight UTC for date-only values, including
+/// the reduced precision forms i.e. `YYYY-MM` and `YYYY`.
+/// With the `lenient` feature, also accepts Unix timestamps (in seconds).
+pub(crate) fn try_parse_date(text: &str) -> Option<time::OffsetDateTime> {
+    use time::{format_description::well_known::Iso8601, OffsetDateTime};
+
+    if let Ok(datetime) = OffsetDateTime::parse(text, &Iso8601::PARSING) {
+        return Some(datetime);
+    }
+
+    if let Some(date) = try_parse_calendar_date(text) {
+        return Some(date.midnight().assume_utc());
+    }
+
+    #[cfg(feature = "lenient")]
+    if let Ok(timestamp) = text.parse::<i64>() {
+        return OffsetDateTime::from_unix_timestamp(timestamp).ok();
+    }
+
+    None
+}
+
+/// Parses the date-only value, including the reduced precision forms.
+fn try_parse_calendar_date(text: &str) -> Option<time::Date> {
+    use time::{format_description::well_known::Iso8601, Date, Month};
+
+    if let Ok(date) = Date::parse(text, &Iso8601::PARSING) {
+        return Some(date);
+    }
+
+    let (year, month) = text.split_once('-').unwrap_or((text, "01"));
+    if year.len() != 4 || month.len() != 2 {
+        return None;
+    }
+
+    let year = year.parse().ok()?;
+    let month = Month::try_from(month.parse::<u8>().ok()?).ok()?;
+    Date::from_calendar_date(year, month, 1).ok()
+}
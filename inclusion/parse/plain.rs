@@ -1,9 +1,10 @@
-use std::io::BufRead;
+use std::io::{BufRead, Read};
 
 use countio::Counter;
 use url::Url;
 
-use crate::parse::{try_if_readable, Parser};
+use crate::parse::{Limits, Parser};
+use crate::record::URL_LEN_LIMIT;
 use crate::{Error, Result};
 
 /// Sitemap parser for the simple TXT file that contains one URL per line.
@@ -15,6 +16,9 @@ use crate::{Error, Result};
 /// https://www.example.com/file2.html
 /// ```
 ///
+/// Blank lines and `#`-prefixed comments are ignored, lines with invalid
+/// URLs are skipped. See [`PlainParser::ignored`] and [`PlainParser::skipped`].
+///
 /// Enforces total written/read bytes and total records limits.
 /// See [Error].
 ///
@@ -33,6 +37,9 @@ use crate::{Error, Result};
 pub struct PlainParser<R> {
     reader: Counter<R>,
     records: usize,
+    skipped: usize,
+    ignored: usize,
+    limits: Limits,
 }
 
 impl<R> PlainParser<R> {
@@ -41,9 +48,39 @@ impl<R> PlainParser<R> {
         Self {
             reader: Counter::new(reader),
             records: 0,
+            skipped: 0,
+            ignored: 0,
+            limits: Limits::default(),
         }
     }
 
+    /// Overrides the total records and bytes limits. See [`Limits`].
+    pub fn with_limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Returns the total amount of records read so far, including invalid
+    /// ones, but not blank or comment lines.
+    pub fn records_read(&self) -> usize {
+        self.records
+    }
+
+    /// Returns the total amount of skipped records i.e. invalid URLs.
+    pub fn skipped(&self) -> usize {
+        self.skipped
+    }
+
+    /// Returns the total amount of ignored blank or `#`-prefixed comment lines.
+    pub fn ignored(&self) -> usize {
+        self.ignored
+    }
+
+    /// Returns the total amount of bytes read from the underlying reader.
+    pub fn bytes_read(&self) -> usize {
+        self.reader.reader_bytes()
+    }
+
     /// Returns a reference to the underlying reader.
     pub fn get_ref(&self) -> &R {
         self.reader.get_ref()
@@ -60,7 +97,68 @@ impl<R> PlainParser<R> {
     }
 
     pub(crate) fn try_if_readable(&mut self) -> Result<()> {
-        try_if_readable(self.records, self.reader.reader_bytes())
+        self.limits
+            .try_if_readable(self.records, self.reader.reader_bytes())
+    }
+
+    /// Returns the address if the line is a valid URL.
+    /// Trailing whitespace, including `\r\n` and `\n` line endings, is trimmed.
+    /// Enforces [`URL_LEN_LIMIT`], expects at most one more byte than allowed.
+    pub(crate) fn try_parse_line(line: &[u8]) -> Result<Option<Url>> {
+        let line = line.trim_ascii_end();
+        if line.len() > URL_LEN_LIMIT {
+            return Err(Error::UrlLimit { len: line.len() });
+        }
+
+        let line = std::str::from_utf8(line).ok();
+        Ok(line.and_then(|u| Url::parse(u).ok()))
+    }
+
+    /// Returns `true` if the line is neither empty nor a comment.
+    /// Enforces [`URL_LEN_LIMIT`], expects at most one more byte than allowed.
+    pub(crate) fn try_count_line(line: &[u8]) -> Result<bool> {
+        let line = line.strip_suffix(b"\n").unwrap_or(line);
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        if line.len() > URL_LEN_LIMIT {
+            return Err(Error::UrlLimit { len: line.len() });
+        }
+
+        let line = line.trim_ascii();
+        Ok(!line.is_empty() && !line.starts_with(b"#"))
+    }
+
+    /// Returns the amount of non-empty, non-comment lines, without parsing
+    /// them into the [`Url`]s. Enforces the same limits as [`Parser::read`].
+    ///
+    /// ```rust
+    /// use sitemapo::parse::{Parser, PlainParser};
+    ///
+    /// let buf = "https://example.com/a.html\n\n# comment\nhttps://example.com/b.html";
+    /// let parser = PlainParser::new(buf.as_bytes()).unwrap();
+    /// assert_eq!(parser.count_lines().unwrap(), 2);
+    /// ```
+    pub fn count_lines(mut self) -> Result<usize>
+    where
+        R: BufRead,
+    {
+        let mut count = 0;
+        loop {
+            self.try_if_readable()?;
+            let mut buf = Vec::new();
+            let limit = URL_LEN_LIMIT as u64 + 1;
+            let mut reader = (&mut self.reader).take(limit);
+            if reader.read_until(b'\n', &mut buf)? == 0 {
+                return Ok(count);
+            }
+
+            if Self::try_count_line(&buf)? {
+                self.records += 1;
+                self.try_if_readable()?;
+                count += 1;
+            } else {
+                self.ignored += 1;
+            }
+        }
     }
 
     pub(crate) fn try_next_sync(&mut self) -> Result<Option<Url>>
@@ -69,15 +167,23 @@ impl<R> PlainParser<R> {
     {
         loop {
             self.try_if_readable()?;
-            let mut buf = String::new();
-            if self.reader.read_line(&mut buf)? == 0 {
+            let mut buf = Vec::new();
+            let limit = URL_LEN_LIMIT as u64 + 1;
+            let mut reader = (&mut self.reader).take(limit);
+            if reader.read_until(b'\n', &mut buf)? == 0 {
                 return Ok(None);
             }
 
+            if !Self::try_count_line(&buf)? {
+                self.ignored += 1;
+                continue;
+            }
+
             self.records += 1;
-            match Url::parse(buf.as_str()) {
-                Ok(address) => return Ok(Some(address)),
-                Err(_) => continue,
+            self.try_if_readable()?;
+            match Self::try_parse_line(&buf)? {
+                Some(address) => return Ok(Some(address)),
+                None => self.skipped += 1,
             }
         }
     }
@@ -104,6 +210,9 @@ impl<R> std::fmt::Debug for PlainParser<R> {
         f.debug_struct("TxtParser")
             .field("bytes", &self.reader.reader_bytes())
             .field("records", &self.records)
+            .field("skipped", &self.skipped)
+            .field("ignored", &self.ignored)
+            .field("limits", &self.limits)
             .finish()
     }
 }
@@ -111,25 +220,67 @@ impl<R> std::fmt::Debug for PlainParser<R> {
 #[cfg(feature = "tokio")]
 #[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
 mod tokio {
-    use tokio::io::{AsyncBufRead, AsyncBufReadExt};
+    use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncRead, AsyncReadExt, BufReader};
     use url::Url;
 
     use crate::parse::{AsyncParser, PlainParser};
+    use crate::record::URL_LEN_LIMIT;
     use crate::{Error, Result};
 
+    impl<R: AsyncRead + Unpin + Send> PlainParser<BufReader<R>> {
+        /// Creates a new instance with the given unbuffered reader,
+        /// wrapping it into the [`BufReader`].
+        pub async fn new_unbuffered(reader: R) -> Result<Self> {
+            Ok(Self::from_reader(BufReader::new(reader)))
+        }
+    }
+
     impl<R: AsyncBufRead + Unpin + Send> PlainParser<R> {
         pub(crate) async fn try_next_async(&mut self) -> Result<Option<Url>> {
             loop {
                 self.try_if_readable()?;
-                let mut buf = String::new();
-                if self.reader.read_line(&mut buf).await? == 0 {
+                let mut buf = Vec::new();
+                let limit = URL_LEN_LIMIT as u64 + 1;
+                let mut reader = (&mut self.reader).take(limit);
+                if reader.read_until(b'\n', &mut buf).await? == 0 {
                     return Ok(None);
                 }
 
+                if !Self::try_count_line(&buf)? {
+                    self.ignored += 1;
+                    continue;
+                }
+
                 self.records += 1;
-                match Url::parse(buf.as_str()) {
-                    Ok(address) => return Ok(Some(address)),
-                    Err(_) => continue,
+                self.try_if_readable()?;
+                match Self::try_parse_line(&buf)? {
+                    Some(address) => return Ok(Some(address)),
+                    None => self.skipped += 1,
+                }
+            }
+        }
+    }
+
+    impl<R: AsyncBufRead + Unpin + Send> PlainParser<R> {
+        /// Returns the amount of non-empty, non-comment lines.
+        /// See [`PlainParser::count_lines`].
+        pub async fn count_lines_async(mut self) -> Result<usize> {
+            let mut count = 0;
+            loop {
+                self.try_if_readable()?;
+                let mut buf = Vec::new();
+                let limit = URL_LEN_LIMIT as u64 + 1;
+                let mut reader = (&mut self.reader).take(limit);
+                if reader.read_until(b'\n', &mut buf).await? == 0 {
+                    return Ok(count);
+                }
+
+                if Self::try_count_line(&buf)? {
+                    self.records += 1;
+                    self.try_if_readable()?;
+                    count += 1;
+                } else {
+                    self.ignored += 1;
                 }
             }
         }
@@ -177,6 +328,124 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn synk_counters() -> Result<(), Error> {
+        use crate::parse::Parser;
+
+        let buf = "https://www.example.com/file1.html\nfoo\n\n  \n# comment\n";
+        let mut parser = PlainParser::new(buf.as_bytes())?;
+        while parser.read()?.is_some() {}
+        assert_eq!(parser.records_read(), 2);
+        assert_eq!(parser.skipped(), 1);
+        assert_eq!(parser.ignored(), 3);
+        assert_eq!(parser.bytes_read(), buf.len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn synk_limits() -> Result<(), Error> {
+        use crate::parse::{Limits, Parser};
+
+        let limits = Limits {
+            records: 2,
+            ..Limits::default()
+        };
+
+        let buf = "https://www.example.com/file1.html\nhttps://www.example.com/file2.html\n";
+        let mut parser = PlainParser::new(buf.as_bytes())?.with_limits(limits);
+        assert!(parser.read()?.is_some());
+        assert!(parser.read()?.is_some());
+        assert!(parser.read()?.is_none());
+
+        let buf = format!("{buf}https://www.example.com/file3.html\n");
+        let mut parser = PlainParser::new(buf.as_bytes())?.with_limits(limits);
+        assert!(parser.read()?.is_some());
+        assert!(parser.read()?.is_some());
+        assert!(matches!(parser.read(), Err(Error::EntryLimit { .. })));
+
+        Ok(())
+    }
+
+    #[test]
+    fn synk_trailing_whitespace() -> Result<(), Error> {
+        use crate::parse::Parser;
+
+        let buf = "https://x/\r\nhttps://x/a \t\r\nhttps://x/b%20\n";
+        let mut parser = PlainParser::new(buf.as_bytes())?;
+
+        let exp = ["https://x/", "https://x/a", "https://x/b%20"];
+        for exp in exp {
+            let url = parser.read()?.unwrap();
+            assert_eq!(url.as_str(), exp);
+        }
+
+        assert!(parser.read()?.is_none());
+        assert_eq!(parser.skipped(), 0);
+
+        Ok(())
+    }
+
+    const LINES: &str = "https://www.example.com/file1.html\n\
+        \n\
+        # https://www.example.com/file2.html\n\
+        https://www.example.com/file3.html\r\n\
+        https://www.example.com/file4.html";
+
+    #[test]
+    fn synk_count_lines() -> Result<(), Error> {
+        use crate::parse::Parser;
+
+        let mut parser = PlainParser::new(LINES.as_bytes())?;
+        let mut urls = 0;
+        while parser.read()?.is_some() {
+            urls += 1;
+        }
+
+        let parser = PlainParser::new(LINES.as_bytes())?;
+        assert_eq!(parser.count_lines()?, urls);
+        assert_eq!(urls, 3);
+
+        let buf = "a".repeat(100 * 1024);
+        let parser = PlainParser::new(buf.as_bytes())?;
+        let count = parser.count_lines();
+        assert!(matches!(count, Err(Error::UrlLimit { .. })));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn asynk_count_lines() -> Result<(), Error> {
+        use crate::parse::AsyncParser;
+
+        let parser = PlainParser::new(LINES.as_bytes()).await?;
+        assert_eq!(parser.count_lines_async().await?, 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn synk_url_limit() {
+        use crate::parse::Parser;
+
+        let buf = "a".repeat(100 * 1024);
+        let mut parser = PlainParser::new(buf.as_bytes()).unwrap();
+        let url = parser.read();
+        assert!(matches!(url, Err(Error::UrlLimit { .. })));
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn asynk_url_limit() {
+        use crate::parse::AsyncParser;
+
+        let buf = "a".repeat(100 * 1024);
+        let mut parser = PlainParser::new(buf.as_bytes()).await.unwrap();
+        let url = parser.read().await;
+        assert!(matches!(url, Err(Error::UrlLimit { .. })));
+    }
+
     #[cfg(feature = "tokio")]
     #[tokio::test]
     async fn asynk() -> Result<(), Error> {
@@ -0,0 +1,26 @@
+use url::Url;
+
+/// Represents a single localized version of the page i.e. `hreflang` alternate.
+///
+/// ```rust
+/// use url::Url;
+/// use sitemapo::record::*;
+///
+/// let href = Url::parse("https://example.com/de/").unwrap();
+/// let _ = Alternate::new("de", href);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Alternate {
+    pub hreflang: String,
+    pub href: Url,
+}
+
+impl Alternate {
+    /// Creates a new instance with the given language and location.
+    pub fn new(hreflang: &str, href: Url) -> Self {
+        Self {
+            hreflang: hreflang.to_string(),
+            href,
+        }
+    }
+}
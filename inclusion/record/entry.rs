@@ -1,6 +1,11 @@
+use std::path::Path;
+
 use time::OffsetDateTime;
 use url::Url;
 
+use crate::parse::try_parse_date;
+#[cfg(feature = "extension")]
+use crate::record::{Alternate, VideoEntry};
 use crate::record::{Frequency, Priority};
 
 /// Represents a single record in the Text or XML sitemap.
@@ -15,12 +20,30 @@ use crate::record::{Frequency, Priority};
 ///     .with_priority(Priority::MAX)
 ///     .with_frequency(Frequency::Daily);
 /// ```
-#[derive(Debug, Clone)]
+///
+/// Records are compared by all fields, but hashed by their location only.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Entry {
     pub location: Url,
     pub modified: Option<OffsetDateTime>,
     pub priority: Option<Priority>,
     pub frequency: Option<Frequency>,
+    /// Locations of the images from the `image` extension.
+    #[cfg(feature = "extension")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extension")))]
+    pub images: Vec<Url>,
+    /// Videos from the `video` extension.
+    #[cfg(feature = "extension")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extension")))]
+    pub videos: Vec<VideoEntry>,
+    /// Localized versions of the page i.e. `hreflang` alternates.
+    #[cfg(feature = "extension")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extension")))]
+    pub alternates: Vec<Alternate>,
+    /// Attributes of the `url` element e.g. `xml:lang`, as key-value pairs.
+    #[cfg(feature = "extension")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extension")))]
+    pub attributes: Vec<(String, String)>,
 }
 
 impl Entry {
@@ -31,9 +54,33 @@ impl Entry {
             modified: None,
             priority: None,
             frequency: None,
+            #[cfg(feature = "extension")]
+            images: Vec::new(),
+            #[cfg(feature = "extension")]
+            videos: Vec::new(),
+            #[cfg(feature = "extension")]
+            alternates: Vec::new(),
+            #[cfg(feature = "extension")]
+            attributes: Vec::new(),
         }
     }
 
+    /// Creates a new instance with the given location, that was last
+    /// modified at the modification time of the given file.
+    ///
+    /// ```rust
+    /// use url::Url;
+    /// use sitemapo::record::Entry;
+    ///
+    /// let url = Url::parse("https://example.com/").unwrap();
+    /// let entry = Entry::from_path(url, "./Cargo.toml").unwrap();
+    /// assert!(entry.modified.is_some());
+    /// ```
+    pub fn from_path(location: Url, path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let modified = std::fs::metadata(path)?.modified()?;
+        Ok(Self::new(location).with_modified(modified.into()))
+    }
+
     /// Creates a new record with the given modify timestamp.
     pub fn with_modified(mut self, modified: OffsetDateTime) -> Self {
         self.modified = Some(modified);
@@ -51,6 +98,131 @@ impl Entry {
         self.frequency = Some(frequency);
         self
     }
+
+    /// Creates a new record with the listed query parameters removed from
+    /// the location e.g. tracking parameters. Parameters ending with `*`
+    /// match by the prefix, so `utm_*` removes all `utm_` parameters.
+    /// Remaining parameters are kept byte-for-byte.
+    ///
+    /// ```rust
+    /// use url::Url;
+    /// use sitemapo::record::Entry;
+    ///
+    /// let url = Url::parse("https://example.com/?id=1&utm_source=x&fbclid=y").unwrap();
+    /// let entry = Entry::new(url).with_cleaned_location(&["utm_*", "fbclid"]);
+    /// assert_eq!(entry.location.as_str(), "https://example.com/?id=1");
+    /// ```
+    pub fn with_cleaned_location(mut self, params: &[&str]) -> Self {
+        let is_tracking = |key: &str| {
+            params.iter().any(|param| match param.strip_suffix('*') {
+                Some(prefix) => key.starts_with(prefix),
+                None => key == *param,
+            })
+        };
+
+        let Some(query) = self.location.query() else {
+            return self;
+        };
+
+        // Matches the decoded key, but keeps the raw segment.
+        let is_kept = |segment: &&str| {
+            let pair = url::form_urlencoded::parse(segment.as_bytes()).next();
+            !pair.is_some_and(|(key, _)| is_tracking(&key))
+        };
+
+        let segments: Vec<_> = query.split('&').collect();
+        let kept: Vec<_> = segments.iter().copied().filter(is_kept).collect();
+        if kept.len() == segments.len() {
+            return self;
+        }
+
+        let query = kept.join("&");
+        let query = Some(query.as_str()).filter(|u| !u.is_empty());
+        self.location.set_query(query);
+        self
+    }
+
+    /// Creates a new record with the given image location appended.
+    #[cfg(feature = "extension")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extension")))]
+    pub fn with_image(mut self, image: Url) -> Self {
+        self.images.push(image);
+        self
+    }
+
+    /// Creates a new record with the given video appended.
+    #[cfg(feature = "extension")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extension")))]
+    pub fn with_video(mut self, video: VideoEntry) -> Self {
+        self.videos.push(video);
+        self
+    }
+
+    /// Creates a new record with the given localized version appended.
+    #[cfg(feature = "extension")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extension")))]
+    pub fn with_alternate(mut self, alternate: Alternate) -> Self {
+        self.alternates.push(alternate);
+        self
+    }
+
+    /// Creates a new record with the given attribute of the `url` element appended.
+    ///
+    /// ```rust
+    /// use url::Url;
+    /// use sitemapo::record::Entry;
+    ///
+    /// let _ = Entry::new(Url::parse("https://example.com/").unwrap())
+    ///     .with_attribute("xml:lang", "en");
+    /// ```
+    #[cfg(feature = "extension")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extension")))]
+    pub fn with_attribute(mut self, key: &str, value: &str) -> Self {
+        self.attributes.push((key.to_string(), value.to_string()));
+        self
+    }
+}
+
+/// Parses a single line of the text sitemap i.e. the location, optionally
+/// followed by the whitespace and the W3C Datetime of the last modification.
+///
+/// Returns `None` if the location or the trailing date is not valid.
+///
+/// ```rust
+/// use time::macros::datetime;
+/// use sitemapo::record::parse_text_line;
+///
+/// let rec = parse_text_line("https://example.com/").unwrap();
+/// assert_eq!(rec.location.as_str(), "https://example.com/");
+/// assert_eq!(rec.modified, None);
+///
+/// let rec = parse_text_line("https://example.com/ 2022-06-04").unwrap();
+/// assert_eq!(rec.modified, Some(datetime!(2022-06-04 0:00 UTC)));
+///
+/// assert!(parse_text_line("example.com 2022-06-04").is_none());
+/// assert!(parse_text_line("https://example.com/ tomorrow").is_none());
+/// ```
+pub fn parse_text_line(line: &str) -> Option<Entry> {
+    let mut parts = line.split_whitespace();
+    let location = Url::parse(parts.next()?).ok()?;
+    let modified = match parts.next() {
+        Some(date) => Some(try_parse_date(date)?),
+        None => None,
+    };
+
+    if parts.next().is_some() {
+        return None;
+    }
+
+    let mut entry = Entry::new(location);
+    entry.modified = modified;
+    Some(entry)
+}
+
+impl std::hash::Hash for Entry {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.location.hash(state)
+    }
 }
 
 impl From<Url> for Entry {
@@ -58,3 +230,86 @@ impl From<Url> for Entry {
         Entry::new(location)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+    use std::fs::File;
+
+    use time::macros::datetime;
+    use url::Url;
+
+    use crate::record::{Entry, Priority};
+
+    #[test]
+    fn eq() {
+        let url = Url::parse("https://example.com/").unwrap();
+        let a = Entry::new(url.clone()).with_priority(Priority::MAX);
+        let b = Entry::new(url.clone()).with_priority(Priority::MAX);
+        let c = Entry::new(url).with_priority(Priority::MIN);
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn cleaned_location() {
+        let url = Url::parse("https://example.com/a?utm_source=news&id=1#top").unwrap();
+        let entry = Entry::new(url).with_cleaned_location(&["utm_source"]);
+        assert_eq!(entry.location.as_str(), "https://example.com/a?id=1#top");
+
+        let url = Url::parse("https://example.com/a?utm_source=news").unwrap();
+        let entry = Entry::new(url).with_cleaned_location(&["utm_source"]);
+        assert_eq!(entry.location.as_str(), "https://example.com/a");
+
+        let url = Url::parse("https://example.com/a").unwrap();
+        let entry = Entry::new(url).with_cleaned_location(&["utm_source"]);
+        assert_eq!(entry.location.as_str(), "https://example.com/a");
+    }
+
+    #[test]
+    fn cleaned_location_raw() {
+        let url = Url::parse("https://example.com/a?page&q=a%20b&utm_%73ource=x").unwrap();
+        let entry = Entry::new(url).with_cleaned_location(&["utm_source"]);
+        assert_eq!(
+            entry.location.as_str(),
+            "https://example.com/a?page&q=a%20b"
+        );
+
+        for url in [
+            "https://example.com/a?page&q=a%20b",
+            "https://example.com/a?a=1;b=2",
+        ] {
+            let entry = Entry::new(Url::parse(url).unwrap());
+            let entry = entry.with_cleaned_location(&["utm_source"]);
+            assert_eq!(entry.location.as_str(), url);
+        }
+    }
+
+    #[test]
+    fn from_path() -> std::io::Result<()> {
+        let path = std::env::temp_dir().join(format!("sitemapo-{}.html", std::process::id()));
+        let file = File::create(&path)?;
+        let mtime = datetime!(2020-01-01 0:00 UTC);
+        file.set_modified(mtime.into())?;
+
+        let url = Url::parse("https://example.com/").unwrap();
+        let entry = Entry::from_path(url, &path);
+        std::fs::remove_file(&path)?;
+        assert_eq!(entry?.modified, Some(mtime));
+
+        let url = Url::parse("https://example.com/").unwrap();
+        assert!(Entry::from_path(url, &path).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn dedup() {
+        let a = Entry::new(Url::parse("https://example.com/a").unwrap());
+        let b = Entry::new(Url::parse("https://example.com/b").unwrap());
+        let records = [a.clone(), b, a];
+
+        let records: HashSet<_> = records.into_iter().collect();
+        assert_eq!(records.len(), 2);
+    }
+}
@@ -1,7 +1,7 @@
 use std::fmt;
 
 use thiserror::Error;
-use time::{ext::NumericalDuration, OffsetDateTime};
+use time::{ext::NumericalDuration, Month, OffsetDateTime};
 
 /// [Frequency] parsing error.
 #[derive(Debug, Error)]
@@ -12,7 +12,7 @@ pub struct FrequencyError;
 ///
 /// This value provides general information to search engines and
 /// may not correlate exactly to how often they crawl the page.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Frequency {
     /// Describes documents that change each time they are accessed.
     Always,
@@ -56,7 +56,9 @@ impl Frequency {
     }
 
     /// Calculates the date when the entry becomes outdated.
-    /// TODO: Fix months and years.
+    ///
+    /// Monthly and yearly frequencies follow the calendar i.e. land on the same
+    /// day of the month, clamped to the last day of the shorter month.
     ///
     /// ```rust
     /// use time::macros::datetime;
@@ -64,7 +66,15 @@ impl Frequency {
     ///
     /// let d0 = datetime!(2022-09-12 12:00 UTC);
     /// let rs = Frequency::Monthly.next_date(d0);
-    /// assert_eq!(rs.unwrap(), datetime!(2022-10-12 12:00 UTC))
+    /// assert_eq!(rs.unwrap(), datetime!(2022-10-12 12:00 UTC));
+    ///
+    /// let d0 = datetime!(2024-01-31 12:00 UTC);
+    /// let rs = Frequency::Monthly.next_date(d0);
+    /// assert_eq!(rs.unwrap(), datetime!(2024-02-29 12:00 UTC));
+    ///
+    /// let d0 = datetime!(2024-02-29 12:00 UTC);
+    /// let rs = Frequency::Yearly.next_date(d0);
+    /// assert_eq!(rs.unwrap(), datetime!(2025-02-28 12:00 UTC));
     /// ```
     pub fn next_date(&self, date: OffsetDateTime) -> Option<OffsetDateTime> {
         use Frequency::*;
@@ -73,11 +83,23 @@ impl Frequency {
             Hourly => Some(date + 1.hours()),
             Daily => Some(date + 1.days()),
             Weekly => Some(date + 7.days()),
-            Monthly => Some(date + 30.days()),
-            Yearly => Some(date + 365.days()),
+            Monthly => Self::add_months(date, 1),
+            Yearly => Self::add_months(date, 12),
         }
     }
 
+    /// Adds the number of calendar months, clamping the day of the month.
+    fn add_months(date: OffsetDateTime, months: u8) -> Option<OffsetDateTime> {
+        let months = date.month() as i32 - 1 + months as i32;
+        let year = date.year() + months / 12;
+        let month = Month::try_from((months % 12 + 1) as u8).ok()?;
+
+        let day = date.day().min(month.length(year));
+        // Resets the day first, as the intermediate date may be invalid otherwise.
+        let date = date.replace_day(1).ok()?.replace_year(year).ok()?;
+        date.replace_month(month).ok()?.replace_day(day).ok()
+    }
+
     /// Calculates if the entry is currently outdated.
     ///
     /// ```rust
@@ -98,6 +120,32 @@ impl Frequency {
             },
         }
     }
+
+    /// Returns `true` if the entry last crawled at the given date
+    /// should be crawled again.
+    ///
+    /// Documents that change on every access (i.e. [`Frequency::Always`])
+    /// should always be recrawled, while archived documents
+    /// (i.e. [`Frequency::Never`]) should never be recrawled.
+    /// See [`Frequency::is_outdated`].
+    ///
+    /// ```rust
+    /// use time::macros::datetime;
+    /// use sitemapo::record::Frequency;
+    ///
+    /// let last = datetime!(2022-09-12 12:00 UTC);
+    /// let now = datetime!(2022-09-13 12:00 UTC);
+    /// assert!(Frequency::Daily.should_recrawl(last, now));
+    /// assert!(!Frequency::Weekly.should_recrawl(last, now));
+    /// assert!(!Frequency::Never.should_recrawl(last, now));
+    /// ```
+    pub fn should_recrawl(&self, last: OffsetDateTime, now: OffsetDateTime) -> bool {
+        match self {
+            Self::Always => true,
+            Self::Never => false,
+            _ => self.is_outdated(last, now),
+        }
+    }
 }
 
 impl fmt::Display for Frequency {
@@ -123,3 +171,56 @@ impl TryFrom<&str> for Frequency {
         Self::parse(value)
     }
 }
+
+#[cfg(test)]
+mod recrawl {
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn should_recrawl(frequency: Frequency) -> [bool; 3] {
+        let last = datetime!(2022-09-12 12:00 UTC);
+        let nows = [
+            datetime!(2022-09-12 12:00 UTC),
+            datetime!(2022-09-12 13:00 UTC),
+            datetime!(2023-09-12 12:00 UTC),
+        ];
+
+        nows.map(|now| frequency.should_recrawl(last, now))
+    }
+
+    #[test]
+    fn always() {
+        assert_eq!(should_recrawl(Frequency::Always), [true, true, true]);
+    }
+
+    #[test]
+    fn hourly() {
+        assert_eq!(should_recrawl(Frequency::Hourly), [false, true, true]);
+    }
+
+    #[test]
+    fn daily() {
+        assert_eq!(should_recrawl(Frequency::Daily), [false, false, true]);
+    }
+
+    #[test]
+    fn weekly() {
+        assert_eq!(should_recrawl(Frequency::Weekly), [false, false, true]);
+    }
+
+    #[test]
+    fn monthly() {
+        assert_eq!(should_recrawl(Frequency::Monthly), [false, false, true]);
+    }
+
+    #[test]
+    fn yearly() {
+        assert_eq!(should_recrawl(Frequency::Yearly), [false, false, true]);
+    }
+
+    #[test]
+    fn never() {
+        assert_eq!(should_recrawl(Frequency::Never), [false, false, false]);
+    }
+}
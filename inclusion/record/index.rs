@@ -11,7 +11,7 @@ use url::Url;
 /// let _ = Index::new(Url::parse("https://example.com/").unwrap())
 ///     .with_modified(datetime!(2020-01-01 0:00 UTC));
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Index {
     pub location: Url,
     pub modified: Option<OffsetDateTime>,
@@ -40,3 +40,9 @@ impl From<Url> for Index {
         Index::new(location)
     }
 }
+
+impl From<(Url, Option<OffsetDateTime>)> for Index {
+    fn from((location, modified): (Url, Option<OffsetDateTime>)) -> Self {
+        Self { location, modified }
+    }
+}
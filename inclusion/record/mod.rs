@@ -1,12 +1,22 @@
+#[cfg(feature = "extension")]
+#[cfg_attr(docsrs, doc(cfg(feature = "extension")))]
+pub use alternate::*;
 pub use entry::*;
 pub use frequency::*;
 pub use index::*;
 pub use priority::*;
+#[cfg(feature = "extension")]
+#[cfg_attr(docsrs, doc(cfg(feature = "extension")))]
+pub use video::*;
 
+#[cfg(feature = "extension")]
+mod alternate;
 mod entry;
 mod frequency;
 mod index;
 mod priority;
+#[cfg(feature = "extension")]
+mod video;
 
 /// All formats limit a single sitemap to 50,000 URLs.
 /// See [Build and submit a Sitemap](https://developers.google.com/search/docs/crawling-indexing/sitemaps/build-sitemap#sitemap-best-practices).
@@ -31,3 +41,44 @@ pub(crate) const URL: &str = "url";
 
 pub(crate) const SITEMAP_INDEX: &str = "sitemapindex";
 pub(crate) const SITEMAP: &str = "sitemap";
+
+pub(crate) const XMLNS: (&str, &str) = ("xmlns", "http://www.sitemaps.org/schemas/sitemap/0.9");
+
+pub(crate) const XSI_XMLNS: (&str, &str) =
+    ("xmlns:xsi", "http://www.w3.org/2001/XMLSchema-instance");
+pub(crate) const XSI_SCHEMA_LOCATION: &str = "xsi:schemaLocation";
+pub(crate) const URL_SET_SCHEMA: &str =
+    "http://www.sitemaps.org/schemas/sitemap/0.9 http://www.sitemaps.org/schemas/sitemap/0.9/sitemap.xsd";
+pub(crate) const SITEMAP_INDEX_SCHEMA: &str =
+    "http://www.sitemaps.org/schemas/sitemap/0.9 http://www.sitemaps.org/schemas/sitemap/0.9/siteindex.xsd";
+
+#[cfg(feature = "extension")]
+pub(crate) const IMAGE_XMLNS: (&str, &str) = (
+    "xmlns:image",
+    "http://www.google.com/schemas/sitemap-image/1.1",
+);
+#[cfg(feature = "extension")]
+pub(crate) const IMAGE: &str = "image:image";
+#[cfg(feature = "extension")]
+pub(crate) const IMAGE_LOCATION: &str = "image:loc";
+
+#[cfg(feature = "extension")]
+pub(crate) const VIDEO_XMLNS: (&str, &str) = (
+    "xmlns:video",
+    "http://www.google.com/schemas/sitemap-video/1.1",
+);
+#[cfg(feature = "extension")]
+pub(crate) const VIDEO: &str = "video:video";
+#[cfg(feature = "extension")]
+pub(crate) const VIDEO_THUMBNAIL_LOCATION: &str = "video:thumbnail_loc";
+#[cfg(feature = "extension")]
+pub(crate) const VIDEO_TITLE: &str = "video:title";
+#[cfg(feature = "extension")]
+pub(crate) const VIDEO_DESCRIPTION: &str = "video:description";
+#[cfg(feature = "extension")]
+pub(crate) const VIDEO_CONTENT_LOCATION: &str = "video:content_loc";
+
+#[cfg(feature = "extension")]
+pub(crate) const XHTML_XMLNS: (&str, &str) = ("xmlns:xhtml", "http://www.w3.org/1999/xhtml");
+#[cfg(feature = "extension")]
+pub(crate) const XHTML_LINK: &str = "xhtml:link";
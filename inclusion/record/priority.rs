@@ -45,6 +45,7 @@ impl Priority {
     /// let frequency = Priority::new_fallback(2.6f32);
     /// assert_eq!(frequency.as_inner(), 1.0);
     /// ```
+    #[allow(clippy::manual_clamp)] // Unlike clamp, maps NaN to 0.0.
     pub fn new_fallback(priority: f32) -> Self {
         Self(priority.max(0.0).min(1.0))
     }
@@ -62,6 +63,25 @@ impl Priority {
         Self::new(priority)
     }
 
+    /// Returns the priority increased by the delta (or decreased if negative),
+    /// clamped into the acceptable range. Ignores the NaN delta.
+    ///
+    /// ```rust
+    /// use sitemapo::record::Priority;
+    ///
+    /// let priority = Priority::AVG.clamp_add(0.7);
+    /// assert_eq!(priority, Priority::MAX);
+    ///
+    /// let priority = Priority::AVG.clamp_add(-0.2);
+    /// assert!(priority < Priority::AVG);
+    /// ```
+    pub fn clamp_add(self, delta: f32) -> Self {
+        match delta.is_nan() {
+            true => self,
+            false => Self::new_fallback(self.0 + delta),
+        }
+    }
+
     /// Returns the internal value.
     pub fn as_inner(&self) -> f32 {
         self.0
@@ -77,6 +97,29 @@ impl Priority {
     pub const MAX: Self = Self(1.0);
 }
 
+// The value is never NaN, as it is always within the range.
+impl Eq for Priority {}
+
+impl std::hash::Hash for Priority {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        // Adding the positive zero maps -0.0 to 0.0, as they are equal.
+        (self.0 + 0.0).to_bits().hash(state)
+    }
+}
+
+impl PartialOrd for Priority {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Priority {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        // Adding the positive zero maps -0.0 to 0.0, as they are equal.
+        (self.0 + 0.0).total_cmp(&(other.0 + 0.0))
+    }
+}
+
 impl Default for Priority {
     fn default() -> Self {
         Self::AVG
@@ -96,3 +139,26 @@ impl TryFrom<&str> for Priority {
         Self::parse(value)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn ord() {
+        let mut priorities = [Priority::MAX, Priority::MIN, Priority::AVG];
+        priorities.sort();
+        assert_eq!(priorities, [Priority::MIN, Priority::AVG, Priority::MAX]);
+
+        let zero = Priority::new(-0.0).unwrap();
+        assert_eq!(zero.cmp(&Priority::MIN), std::cmp::Ordering::Equal);
+    }
+
+    #[test]
+    fn clamp_add() {
+        assert_eq!(Priority::MAX.clamp_add(0.1), Priority::MAX);
+        assert_eq!(Priority::MIN.clamp_add(-0.1), Priority::MIN);
+        assert_eq!(Priority::AVG.clamp_add(0.25).as_inner(), 0.75);
+        assert_eq!(Priority::AVG.clamp_add(f32::NAN), Priority::AVG);
+    }
+}
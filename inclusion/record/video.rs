@@ -0,0 +1,38 @@
+use url::Url;
+
+/// Represents a single video from the `video` sitemap extension.
+///
+/// ```rust
+/// use url::Url;
+/// use sitemapo::record::*;
+///
+/// let thumbnail = Url::parse("https://example.com/thumb.jpg").unwrap();
+/// let content = Url::parse("https://example.com/video.mp4").unwrap();
+/// let _ = VideoEntry::new(thumbnail, "Title", "Description")
+///     .with_content(content);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VideoEntry {
+    pub thumbnail_location: Url,
+    pub title: String,
+    pub description: String,
+    pub content_location: Option<Url>,
+}
+
+impl VideoEntry {
+    /// Creates a new instance with the given required fields.
+    pub fn new(thumbnail_location: Url, title: &str, description: &str) -> Self {
+        Self {
+            thumbnail_location,
+            title: title.to_string(),
+            description: description.to_string(),
+            content_location: None,
+        }
+    }
+
+    /// Creates a new record with the given content location.
+    pub fn with_content(mut self, content_location: Url) -> Self {
+        self.content_location = Some(content_location);
+        self
+    }
+}